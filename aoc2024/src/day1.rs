@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use super::Question;
+use aoc_helpers::error::{parse_in, AocError, ParseContext};
+
+use aoc_helpers::questions::{Answer, RawQuestion};
 
 question!(Day1, validate = [
     {
@@ -12,20 +14,20 @@ question!(Day1, validate = [
         3   9
         3   3
         "#,
-        part1: "11",
-        part2: "31"
+        part1: 11,
+        part2: 31
     }
 ]);
 
-impl Question for Day1 {
-    fn part1(input: &str) -> Result<String, Box<dyn std::error::Error>> {
+impl RawQuestion for Day1 {
+    fn part1(input: &str) -> Result<Answer, AocError> {
         let mut left = Vec::new();
         let mut right = Vec::new();
         
-        for line in input.lines() {
+        for (i, line) in input.lines().enumerate() {
             if let Some((l, r)) = line.trim().split_once("   ") {
-                left.push(l.parse::<u32>()?);
-                right.push(r.parse::<u32>()?);
+                left.push(parse_in::<u32>(line, l, "expected a location ID").on_line(i + 1)?);
+                right.push(parse_in::<u32>(line, r, "expected a location ID").on_line(i + 1)?);
             }
         }
 
@@ -39,18 +41,18 @@ impl Question for Day1 {
             sum += l.abs_diff(*r);
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(input: &str) -> Result<String, Box<dyn std::error::Error>> {
+    fn part2(input: &str) -> Result<Answer, AocError> {
         let mut left = Vec::new();
         let mut right: HashMap<u32, u32> = HashMap::new();
         
-        for line in input.lines() {
+        for (i, line) in input.lines().enumerate() {
             if let Some((l, r)) = line.trim().split_once("   ") {
-                left.push(l.parse::<u32>()?);
+                left.push(parse_in::<u32>(line, l, "expected a location ID").on_line(i + 1)?);
 
-                let r = r.parse::<u32>()?;
+                let r = parse_in::<u32>(line, r, "expected a location ID").on_line(i + 1)?;
                 right.entry(r).and_modify(|v| *v += 1).or_insert(1);
             }
         }
@@ -62,6 +64,6 @@ impl Question for Day1 {
             }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 }
@@ -1,6 +1,6 @@
-use std::path::Display;
+use aoc_helpers::error::{parse_in, AocError, ParseContext};
 
-use super::Question;
+use aoc_helpers::questions::{Answer, RawQuestion};
 
 question!(Day2, validate = [
     {
@@ -12,26 +12,26 @@ question!(Day2, validate = [
         8 6 4 4 1
         1 3 6 7 9
         "#,
-        part1: "2",
-        part2: "4"
+        part1: 2,
+        part2: 4
     }
 ]);
 
-impl Question for Day2 {
-    fn part1(input: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let reports = input.lines().map(Report::try_from).collect::<Result<Vec<Report>, _>>()?;
+impl RawQuestion for Day2 {
+    fn part1(input: &str) -> Result<Answer, AocError> {
+        let reports = input.lines().enumerate().map(|(i, line)| Report::try_from(line).on_line(i + 1)).collect::<Result<Vec<Report>, _>>()?;
 
         let safe_reports = reports.iter().filter(|report| report.is_safe()).count();
 
-        Ok(safe_reports.to_string())
+        Ok(safe_reports.into())
     }
 
-    fn part2(input: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let reports = input.lines().map(Report::try_from).collect::<Result<Vec<Report>, _>>()?;
+    fn part2(input: &str) -> Result<Answer, AocError> {
+        let reports = input.lines().enumerate().map(|(i, line)| Report::try_from(line).on_line(i + 1)).collect::<Result<Vec<Report>, _>>()?;
 
         let safe_reports = reports.iter().filter(|report| report.is_safe_with_balancer()).count();
 
-        Ok(safe_reports.to_string())
+        Ok(safe_reports.into())
     }
 }
 
@@ -117,12 +117,12 @@ impl std::fmt::Display for Report {
 }
 
 impl TryFrom<&str> for Report {
-    type Error = Box<dyn std::error::Error>;
+    type Error = AocError;
 
     fn try_from(line: &str) -> Result<Self, Self::Error> {
         let mut levels = Vec::new();
         for number in line.split_whitespace() {
-            levels.push(number.parse::<u32>()?);
+            levels.push(parse_in(line, number, "expected a level")?);
         }
 
         Ok(Self { levels })
@@ -1,31 +1,31 @@
-use std::ops::Index;
+use aoc_helpers::error::AocError;
 
-use super::Question;
+use aoc_helpers::questions::{Answer, RawQuestion};
 
 question!(Day3, validate = [
     {
         input: r#"
         xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
         "#,
-        part1: "161"
+        part1: 161
     },
     {
         input: "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
-        part2: "48"
+        part2: 48
     }
 ]);
 
-impl Question for Day3 {
-    fn part1(input: &str) -> Result<String, Box<dyn std::error::Error>> {
+impl RawQuestion for Day3 {
+    fn part1(input: &str) -> Result<Answer, AocError> {
         let iter = MulExtractor { input, pos: 0 };
         let mut sum = 0;
         for (left, right) in iter {
             sum += left * right;
         }
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(input: &str) -> Result<String, Box<dyn std::error::Error>> {
+    fn part2(input: &str) -> Result<Answer, AocError> {
         let mut sum = 0;
         let dos = input.split("do()");
         for do_ in dos {
@@ -37,7 +37,7 @@ impl Question for Day3 {
             }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 }
 
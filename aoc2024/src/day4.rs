@@ -1,6 +1,6 @@
-use crate::helpers;
+use aoc_helpers::error::AocError;
 
-use super::Question;
+use aoc_helpers::questions::{Answer, RawQuestion};
 
 question!(Day4, validate = [
     {
@@ -16,21 +16,21 @@ question!(Day4, validate = [
         MAMMMXMMMM
         MXMXAXMASX
         "#,
-        part1: "18",
-        part2: "9"
+        part1: 18,
+        part2: 9
     }
 ]);
 
-impl Question for Day4 {
-    fn part1(input: &str) -> Result<String, Box<dyn std::error::Error>> {
+impl RawQuestion for Day4 {
+    fn part1(input: &str) -> Result<Answer, AocError> {
         let grid = input.parse::<WordSearch>()?;
 
-        Ok(grid.count_xmas().to_string())
+        Ok(grid.count_xmas().into())
     }
 
-    fn part2(input: &str) -> Result<String, Box<dyn std::error::Error>> {
+    fn part2(input: &str) -> Result<Answer, AocError> {
         let grid = input.parse::<WordSearch>()?;
-        Ok(grid.count_x_mas().to_string())
+        Ok(grid.count_x_mas().into())
     }
 }
 
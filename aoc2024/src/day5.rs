@@ -1,6 +1,8 @@
 use std::{collections::{HashMap, HashSet}, fmt::Display, str::FromStr};
 
-use super::Question;
+use aoc_helpers::error::{parse_in, AocError, ParseContext};
+
+use aoc_helpers::questions::{Answer, Question};
 
 question!(Day5, validate = [
     {
@@ -34,15 +36,19 @@ question!(Day5, validate = [
         61,13,29
         97,13,75,29,47
         "#,
-        part1: "143",
-        part2: "123"
+        part1: 143,
+        part2: 123
     }
 ]);
 
 impl Question for Day5 {
-    fn part1(input: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let input: Input = input.parse()?;
+    type Parsed = Input;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input.parse()
+    }
 
+    fn part1(input: &Input) -> Result<Answer, AocError> {
         let mut sum = 0;
         for update in &input.updates {
             let ordered = update.order(&input.rules);
@@ -51,12 +57,10 @@ impl Question for Day5 {
             }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(input: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let input: Input = input.parse()?;
-
+    fn part2(input: &Input) -> Result<Answer, AocError> {
         let mut sum = 0;
         for update in &input.updates {
             let ordered = update.order(&input.rules);
@@ -65,37 +69,38 @@ impl Question for Day5 {
             }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 }
 
-struct Input {
+pub struct Input {
     pub rules: HashMap<u32, Rule>,
     pub updates: Vec<Update>,
 }
 
 impl FromStr for Input {
-    type Err = Box<dyn std::error::Error>;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut rules = HashMap::new();
         let mut updates = Vec::new();
         let mut is_rules = true;
 
-        for line in s.trim().lines() {
+        for (i, line) in s.trim().lines().enumerate() {
             if line.is_empty() {
                 is_rules = false;
                 continue;
             }
 
             if is_rules {
-                let (left, right) = line.split_once('|').ok_or("Invalid rule")?;
-                let left = left.trim().parse()?;
-                let right = right.trim().parse()?;
+                let (left, right) = line.split_once('|')
+                    .ok_or_else(|| AocError::parse_in(line, line.trim(), "expected '|' in page ordering rule").on_line(i + 1))?;
+                let left = parse_in(line, left, "expected a page number").on_line(i + 1)?;
+                let right = parse_in(line, right, "expected a page number").on_line(i + 1)?;
 
                 rules.entry(left).or_insert_with(|| Rule { pages: HashSet::new() }).pages.insert(right);
             } else {
-                updates.push(line.parse()?);
+                updates.push(line.parse().on_line(i + 1)?);
             }
         }
 
@@ -104,12 +109,12 @@ impl FromStr for Input {
 }
 
 #[derive(Default)]
-struct Rule {
+pub struct Rule {
     pub pages: HashSet<u32>,
 }
 
 #[derive(PartialEq)]
-struct Update {
+pub struct Update {
     pub pages: Vec<u32>,
 }
 
@@ -147,10 +152,10 @@ impl Update {
 }
 
 impl FromStr for Update {
-    type Err = std::num::ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let pages = s.trim().split(',').map(|s| s.parse()).collect::<Result<Vec<u32>, _>>()?;
+        let pages = s.trim().split(',').map(|p| parse_in(s, p, "expected a page number")).collect::<Result<Vec<u32>, _>>()?;
         Ok(Update { pages })
     }
 }
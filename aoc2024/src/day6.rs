@@ -1,4 +1,7 @@
-use super::Question;
+use aoc_helpers::cancel;
+use aoc_helpers::error::AocError;
+
+use aoc_helpers::questions::{Answer, Question};
 
 question!(Day6, validate = [
     {
@@ -14,23 +17,29 @@ question!(Day6, validate = [
         #.........
         ......#...
         "#,
-        part1: "41",
-        part2: "6"
+        part1: 41,
+        part2: 6
     }
-], skip=true);
+], slow="part 2 tries an obstacle in every position on the path");
 
 impl Question for Day6 {
-    fn part1(input: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let mut map = input.parse::<Map>()?;
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input.parse()
+    }
+
+    fn part1(input: &Map) -> Result<Answer, AocError> {
+        let mut map = input.clone();
 
         let start = map.start().ok_or("No start found")?;
         map.walk_to_end(start);
 
-        Ok(map.visited().to_string())
+        Ok(map.visited().into())
     }
 
-    fn part2(input: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let mut map = input.parse::<Map>()?;
+    fn part2(input: &Map) -> Result<Answer, AocError> {
+        let mut map = input.clone();
         let mut test_map = map.clone();
 
         let start = map.start().ok_or("No start found")?;
@@ -41,6 +50,7 @@ impl Question for Day6 {
 
         let mut count = 0;
         for x in 0..map.0.cols() {
+            cancel::check()?;
             for y in 0..map.0.rows() {
                 if let Some(Tile::Visited) = map.get(x, y) {
                     test_map.set(x, y, Tile::Wall);
@@ -52,11 +62,11 @@ impl Question for Day6 {
             }
         }
 
-        Ok(count.to_string())
+        Ok(count.into())
     }
 }
 
-tile!(Tile {
+tile!(pub Tile {
     Start = '^',
     Empty = '.',
     Wall = '#',
@@ -64,7 +74,7 @@ tile!(Tile {
 });
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
-struct Guard {
+pub struct Guard {
     x: usize,
     y: usize,
     dx: isize,
@@ -90,7 +100,7 @@ impl Guard {
     }
 }
 
-grid!(Map<Tile> => {
+grid!(pub Map<Tile> => {
     pub fn start(&self) -> Option<Guard> {
         for x in 0..self.0.cols() {
             for y in 0..self.0.rows() {
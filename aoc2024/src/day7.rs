@@ -1,6 +1,9 @@
-use std::{fmt::Display, ops::{Div, Rem}, str::FromStr};
+use std::{fmt::Display, str::FromStr};
 
-use super::Question;
+use aoc_helpers::cancel;
+use aoc_helpers::error::{parse_in, AocError, ParseContext};
+
+use aoc_helpers::questions::{Answer, Question};
 
 question!(Day7, validate = [
     {
@@ -15,15 +18,19 @@ question!(Day7, validate = [
         21037: 9 7 18 13
         292: 11 6 16 20
         "#,
-        part1: "3749",
-        part2: "11387"
+        part1: 3749,
+        part2: 11387
     }
 ]);
 
 impl Question for Day7 {
-    fn part1(input: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let equations: Vec<Equation> = input.trim().lines().map(|l| l.parse()).collect::<Result<Vec<_>, _>>()?;
+    type Parsed = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input.trim().lines().enumerate().map(|(i, l)| l.parse().on_line(i + 1)).collect()
+    }
 
+    fn part1(equations: &Vec<Equation>) -> Result<Answer, AocError> {
         let mut sum = 0;
         for eq in equations {
             if eq.is_solvable(false) {
@@ -31,23 +38,23 @@ impl Question for Day7 {
             }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(input: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let equations: Vec<Equation> = input.trim().lines().map(|l| l.parse()).collect::<Result<Vec<_>, _>>()?;
+    fn part2(equations: &Vec<Equation>) -> Result<Answer, AocError> {
         let mut sum = 0;
         for eq in equations {
+            cancel::check()?;
             if eq.is_solvable(true) {
                 sum += eq.value;
             }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 }
 
-struct Equation {
+pub struct Equation {
     value: u64,
     factors: Vec<u64>
 }
@@ -59,12 +66,13 @@ impl Equation {
 }
 
 impl FromStr for Equation {
-    type Err = Box<dyn std::error::Error>;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(':').ok_or("Invalid equation")?;
-        let value = left.trim().parse()?;
-        let factors = right.split_whitespace().map(|f| f.parse()).collect::<Result<Vec<_>, _>>()?;
+        let (left, right) = s.split_once(':')
+            .ok_or_else(|| AocError::parse_in(s, s.trim(), "expected ':' after the test value"))?;
+        let value = parse_in(s, left, "expected a test value")?;
+        let factors = right.split_whitespace().map(|f| parse_in(s, f, "expected a number")).collect::<Result<Vec<_>, _>>()?;
 
         Ok(Equation { value, factors })
     }
@@ -0,0 +1,28 @@
+use aoc_helpers::error::AocError;
+
+use aoc_helpers::questions::{Answer, Question};
+
+question!(DayN, validate = [
+    {
+        input: r#"
+        "#,
+        part1: "",
+        part2: ""
+    }
+]);
+
+impl Question for DayN {
+    type Parsed = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(Answer::default())
+    }
+
+    fn part2(_input: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(Answer::default())
+    }
+}
@@ -0,0 +1,66 @@
+//! The solutions for Advent of Code 2024. Each day's module is public, so that other tools can get at a
+//! day's inputs through the [`ExampleInput`](aoc_helpers::questions::ExampleInput) and
+//! [`RealInput`](aoc_helpers::questions::RealInput) traits which `question!` implements.
+//!
+//! # Examples
+//! ```
+//! use aoc_helpers::inputs::InputStore;
+//! use aoc_helpers::questions::{ExampleInput, RealInput};
+//! use aoc2024::day6::Day6;
+//!
+//! let store = InputStore::new(std::env::temp_dir().join("aoc2024-doc-inputs"));
+//! assert!(Day6::part1_test_input(&store).unwrap().contains('^'));
+//! assert!(Day6::real_input(&store).is_err());
+//! ```
+
+#[macro_use] extern crate aoc_helpers;
+
+use aoc_helpers::questions::Year;
+
+/// The event year these solutions belong to, used by `question!` to find their inputs.
+macro_rules! year {
+    () => { 2024 };
+}
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+
+/// All of the days which have been implemented for 2024, in order.
+pub static YEAR: Year = Year {
+    year: year!(),
+    solutions: &[
+        day1::SOLUTION,
+        day2::SOLUTION,
+        day3::SOLUTION,
+        day4::SOLUTION,
+        day5::SOLUTION,
+        day6::SOLUTION,
+        day7::SOLUTION,
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        for (i, solution) in YEAR.solutions.iter().enumerate() {
+            assert_eq!(solution.name, format!("Day{}", solution.day));
+            assert_eq!(solution.year, YEAR.year);
+            assert!(!solution.examples.is_empty(), "{} has no examples", solution.name);
+
+            if let Some(next) = YEAR.solutions.get(i + 1) {
+                assert!(solution.day < next.day, "{} is registered out of order", next.name);
+            }
+        }
+
+        assert_eq!(YEAR.get(5).map(|s| s.name), Some("Day5"));
+        assert!(YEAR.get(25).is_none());
+    }
+}
@@ -0,0 +1,5 @@
+//! Runs every registered day against its examples and real input, printing a single table of the results.
+
+fn main() -> std::process::ExitCode {
+    aoc_helpers::harness::main(&aoc2024::YEAR, concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs"))
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::error::AocError;
+use crate::questions::Answer;
+use crate::results::PuzzleResult;
+
+/// The answers which have been accepted for each day and part of a year, stored alongside its inputs
+/// (in `inputs/<year>/answers.txt`) so that refactoring can't silently break a day which used to work.
+///
+/// Each line of the file holds a single answer in the form `Day5.1: 143`, and any newlines in an answer
+/// (such as for grids) are escaped as `\n`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnownAnswers {
+    path: PathBuf,
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl KnownAnswers {
+    /// The location of the answers file for the given year, within the inputs directory.
+    pub fn path_for<P: AsRef<Path>>(inputs: P, year: u16) -> PathBuf {
+        inputs.as_ref().join(year.to_string()).join("answers.txt")
+    }
+
+    /// Loads the answers from the given file, which is treated as empty if it doesn't exist yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, AocError> {
+        let path = path.as_ref().to_path_buf();
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(AocError::io(&path, err)),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let ((day, part), answer) = parse_line(line).map_err(|err| err.on_line(i + 1))?;
+            answers.insert((day, part), answer);
+        }
+
+        Ok(Self { path, answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Records the accepted answer for a part, returning the answer it replaced (if there was one).
+    pub fn set(&mut self, day: u8, part: u8, answer: &Answer) -> Option<String> {
+        self.answers.insert((day, part), answer.to_string().trim_end().to_string())
+    }
+
+    /// Compares a result with the known answer for its day and part, if there is one.
+    pub fn check(&self, result: PuzzleResult) -> PuzzleResult {
+        match (result.day(), result.part()) {
+            (Some(day), Some(part)) => match self.get(day, part) {
+                Some(expected) => result.expecting(expected),
+                None => result,
+            },
+            _ => result,
+        }
+    }
+
+    pub fn save(&self) -> Result<(), AocError> {
+        let mut content = String::from("# The accepted answer for each part, checked whenever the solutions are run.\n");
+        for ((day, part), answer) in self.answers.iter() {
+            content.push_str(&format!("Day{day}.{part}: {}\n", answer.replace('\n', "\\n")));
+        }
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| AocError::io(parent, err))?;
+        }
+
+        std::fs::write(&self.path, content).map_err(|err| AocError::io(&self.path, err))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+fn parse_line(line: &str) -> Result<((u8, u8), String), AocError> {
+    let (name, answer) = line.split_once(':')
+        .ok_or_else(|| AocError::parse_in(line, line, "expected 'DayN.P: answer'"))?;
+    let (day, part) = name.trim().strip_prefix("Day").and_then(|name| name.split_once('.'))
+        .ok_or_else(|| AocError::parse_in(line, name.trim(), "expected a puzzle like 'Day5.1'"))?;
+
+    let day = crate::error::parse_in(line, day, "expected a day number")?;
+    let part = match crate::error::parse_in(line, part, "expected a part number")? {
+        part @ (1 | 2) => part,
+        _ => return Err(AocError::parse_in(line, part, "expected part 1 or 2")),
+    };
+
+    Ok(((day, part), answer.trim().replace("\\n", "\n")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::results::Status;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id())).join("2024").join("answers.txt");
+
+        let mut answers = KnownAnswers::load(&path).unwrap();
+        assert_eq!(answers.get(5, 1), None);
+        assert_eq!(answers.set(5, 1, &Answer::from(143)), None);
+        assert_eq!(answers.set(6, 2, &Answer::from("#.\n.#\n".to_string())), None);
+        assert_eq!(answers.set(5, 1, &Answer::from(4872)), Some("143".to_string()));
+        answers.save().unwrap();
+
+        let loaded = KnownAnswers::load(&path).unwrap();
+        std::fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+        assert_eq!(loaded, answers);
+        assert_eq!(loaded.get(5, 1), Some("4872"));
+        assert_eq!(loaded.get(6, 2), Some("#.\n.#"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_line("Day5.1 143").is_err());
+        assert!(parse_line("Day5.3: 143").is_err());
+        assert_eq!(parse_line("Day5.x: 143").unwrap_err().location(), (None, Some(6)));
+        assert_eq!(parse_line("  Day12.2:  abc ").unwrap(), ((12, 2), "abc".to_string()));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = KnownAnswers::default();
+        answers.set(1, 1, &Answer::from(11));
+
+        let result = |answer: i64| PuzzleResult::measure("Day1.1", || Ok(answer)).0;
+        assert_eq!(answers.check(result(11).for_part(1, 1)).status(), Status::Ok);
+        assert_eq!(answers.check(result(12).for_part(1, 1)).status(), Status::WrongAnswer);
+        assert_eq!(answers.check(result(12).for_part(1, 2)).status(), Status::Ok);
+        assert_eq!(answers.check(result(12)).status(), Status::Ok);
+    }
+}
@@ -0,0 +1,101 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::AocError;
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// A flag which the runner sets when a puzzle has run out of time, allowing long-running solutions to
+/// stop cooperatively rather than being abandoned mid-flight.
+///
+/// Solutions don't need to be handed a token explicitly: the runner installs one for the thread each
+/// part runs on, and [`check`] can be called from inside any long loop to bail out once it is cancelled.
+/// Work which is spread across other threads (such as with [`map_reduce`](crate::map_reduce)) can use
+/// [`current`] to take a copy of the token with it.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::cancel::{self, CancellationToken};
+/// use aoc_helpers::error::AocError;
+///
+/// let token = CancellationToken::new();
+/// token.cancel();
+///
+/// let result: Result<(), AocError> = token.run(|| {
+///     for _ in 0.. {
+///         cancel::check()?;
+///     }
+///
+///     Ok(())
+/// });
+///
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks whatever is using this token to stop as soon as it can.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns [`AocError::Cancelled`] if this token has been cancelled.
+    pub fn check(&self) -> Result<(), AocError> {
+        if self.is_cancelled() {
+            Err(AocError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Runs `f` with this token installed as the [`current`] token for the calling thread.
+    pub fn run<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        let result = f();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+        result
+    }
+}
+
+/// The token for the puzzle running on this thread, or one which is never cancelled if there isn't one.
+pub fn current() -> CancellationToken {
+    CURRENT.with(|current| current.borrow().clone()).unwrap_or_default()
+}
+
+/// Returns [`AocError::Cancelled`] if the puzzle running on this thread has been cancelled, so that
+/// solutions can poll it with `cancel::check()?` in their long-running loops.
+pub fn check() -> Result<(), AocError> {
+    CURRENT.with(|current| current.borrow().as_ref().map_or(Ok(()), CancellationToken::check))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current() {
+        assert!(check().is_ok());
+
+        let token = CancellationToken::new();
+        token.run(|| {
+            assert!(check().is_ok());
+            current().cancel();
+            assert!(matches!(check(), Err(AocError::Cancelled)));
+        });
+
+        assert!(token.is_cancelled());
+        assert!(check().is_ok());
+    }
+}
@@ -0,0 +1,395 @@
+use std::error::Error;
+
+/// The error type used by every parser and solution in the crate.
+///
+/// Parse errors carry the line and column (both 1-based) of the text which could not be understood, and
+/// are wrapped in [`AocError::Puzzle`] once they reach the runner so that it is clear which day and part
+/// produced them. Use the alternate format (`{:#}`) to include the full chain of causes, or
+/// [`AocError::diagnostic`] to point at the offending text in the input.
+#[derive(Debug)]
+pub enum AocError {
+    /// Part of the input could not be understood.
+    Parse {
+        line: Option<usize>,
+        column: Option<usize>,
+        text: String,
+        /// The full line of input the error occurred on, used to render diagnostics.
+        snippet: Option<String>,
+        reason: String,
+        cause: Option<Box<dyn Error + Send + Sync>>,
+    },
+    /// The input was understood, but the puzzle could not be solved with it.
+    Solve {
+        reason: String,
+        cause: Option<Box<dyn Error + Send + Sync>>,
+    },
+    /// A file could not be read or written.
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    /// The puzzle did not finish within its time budget.
+    Timeout {
+        limit: std::time::Duration,
+    },
+    /// The puzzle panicked, recording the panic's message and where it happened (if known).
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+    /// The puzzle noticed that it had been cancelled (usually because it timed out) and stopped early.
+    Cancelled,
+    /// The input for a day hasn't been cached, and there was no way of fetching it.
+    MissingInput {
+        year: u16,
+        day: u8,
+        path: String,
+    },
+    /// There is no solution registered for the requested day.
+    NotImplemented {
+        day: u8,
+    },
+    /// An error produced while running a specific day (and part, once parsing has completed).
+    Puzzle {
+        day: u8,
+        part: Option<u8>,
+        source: Box<AocError>,
+    },
+}
+
+impl AocError {
+    /// Reports that the given `text` from the input could not be understood.
+    pub fn parse<T: ToString, R: ToString>(text: T, reason: R) -> Self {
+        Self::Parse { line: None, column: None, text: text.to_string(), snippet: None, reason: reason.to_string(), cause: None }
+    }
+
+    /// Reports that `part` of an input `line` could not be understood, working out which column it
+    /// starts at from its position within the line.
+    pub fn parse_in<R: ToString>(line: &str, part: &str, reason: R) -> Self {
+        let column = (part.as_ptr() as usize).checked_sub(line.as_ptr() as usize)
+            .filter(|offset| *offset <= line.len())
+            .map(|offset| line[..offset].chars().count() + 1);
+
+        Self::Parse { line: None, column, text: part.to_string(), snippet: Some(line.to_string()), reason: reason.to_string(), cause: None }
+    }
+
+    pub fn solve<R: ToString>(reason: R) -> Self {
+        Self::Solve { reason: reason.to_string(), cause: None }
+    }
+
+    pub fn io<P: AsRef<std::path::Path>>(path: P, source: std::io::Error) -> Self {
+        Self::Io { path: path.as_ref().display().to_string(), source }
+    }
+
+    /// Records the underlying error which led to this one.
+    pub fn caused_by<E: Error + Send + Sync + 'static>(mut self, err: E) -> Self {
+        if let Self::Parse { cause, .. } | Self::Solve { cause, .. } = &mut self {
+            *cause = Some(Box::new(err));
+        }
+
+        self
+    }
+
+    /// Records the line of the input on which a parse error occurred.
+    pub fn on_line(mut self, number: usize) -> Self {
+        if let Self::Parse { line, .. } = &mut self {
+            *line = Some(number);
+        }
+
+        self
+    }
+
+    /// Records the full line of input on which a parse error occurred, so that it can be shown in diagnostics.
+    pub fn in_line(mut self, line: &str) -> Self {
+        if let Self::Parse { snippet, .. } = &mut self {
+            *snippet = Some(line.to_string());
+        }
+
+        self
+    }
+
+    /// Records the line and column of the input at which a parse error occurred.
+    pub fn at(mut self, number: usize, col: usize) -> Self {
+        if let Self::Parse { column, .. } = &mut self {
+            *column = Some(col);
+        }
+
+        self.on_line(number)
+    }
+
+    /// Records which day (and part) was being run when this error occurred.
+    pub fn in_puzzle(self, day: u8, part: Option<u8>) -> Self {
+        match self {
+            Self::Puzzle { .. } | Self::NotImplemented { .. } => self,
+            source => Self::Puzzle { day, part, source: Box::new(source) },
+        }
+    }
+
+    /// Renders this error in the style of a compiler diagnostic, showing the offending line of input with a
+    /// caret under the text which could not be understood.
+    pub fn diagnostic(&self) -> Diagnostic<'_> {
+        Diagnostic(self)
+    }
+
+    /// The line and column at which this error occurred in the input, if known.
+    pub fn location(&self) -> (Option<usize>, Option<usize>) {
+        match self {
+            Self::Parse { line, column, .. } => (*line, *column),
+            Self::Puzzle { source, .. } => source.location(),
+            _ => (None, None),
+        }
+    }
+}
+
+impl std::fmt::Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse { line, column, text, reason, .. } => {
+                write!(f, "{reason}")?;
+
+                if !text.is_empty() {
+                    write!(f, " (found '{text}')")?;
+                }
+
+                match (line, column) {
+                    (Some(line), Some(column)) => write!(f, " at line {line}, column {column}")?,
+                    (Some(line), None) => write!(f, " on line {line}")?,
+                    (None, Some(column)) => write!(f, " at column {column}")?,
+                    (None, None) => {},
+                }
+            },
+            Self::Solve { reason, .. } => write!(f, "{reason}")?,
+            Self::Io { path, source } => write!(f, "could not access '{path}': {source}")?,
+            Self::Timeout { limit } => write!(f, "timed out after {:.1}s", limit.as_secs_f64())?,
+            Self::Panicked { message, location: Some(location) } => write!(f, "panicked at {location}: {message}")?,
+            Self::Panicked { message, location: None } => write!(f, "panicked: {message}")?,
+            Self::Cancelled => write!(f, "cancelled before it could finish")?,
+            Self::MissingInput { year, day, path } => write!(f, "there is no input for {year} day {day} at '{path}' (set AOC_SESSION, and AOC_URL to a local TLS proxy, to download it)")?,
+            Self::NotImplemented { day } => write!(f, "Day {day} has not been implemented yet.")?,
+            Self::Puzzle { day, part, source } => {
+                match part {
+                    Some(part) => write!(f, "Day{day}.{part}: ")?,
+                    None => write!(f, "Day{day}: ")?,
+                }
+
+                return std::fmt::Display::fmt(source, f);
+            },
+        }
+
+        if f.alternate() {
+            let mut cause = self.source();
+            while let Some(err) = cause {
+                write!(f, "\n  caused by: {err}")?;
+                cause = err.source();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse { cause, .. } | Self::Solve { cause, .. } => cause.as_ref().map(|err| err.as_ref() as &(dyn Error + 'static)),
+            Self::Io { source, .. } => Some(source),
+            Self::Puzzle { source, .. } => source.source(),
+            Self::Timeout { .. } | Self::Panicked { .. } | Self::Cancelled | Self::MissingInput { .. } | Self::NotImplemented { .. } => None,
+        }
+    }
+}
+
+impl From<&str> for AocError {
+    fn from(reason: &str) -> Self {
+        Self::solve(reason)
+    }
+}
+
+impl From<String> for AocError {
+    fn from(reason: String) -> Self {
+        Self::solve(reason)
+    }
+}
+
+impl From<std::num::ParseIntError> for AocError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        Self::parse("", "expected a number").caused_by(err)
+    }
+}
+
+/// A compiler-style rendering of an [`AocError`], created by [`AocError::diagnostic`].
+///
+/// ```text
+/// error[Day5]: expected '|' in page ordering rule
+///  --> line 5, column 1
+///   |
+/// 5 | 75-29
+///   | ^^^^^
+/// ```
+pub struct Diagnostic<'a>(&'a AocError);
+
+impl Diagnostic<'_> {
+    /// The widest slice of a line which will be shown before it is shortened around the error.
+    const MAX_WIDTH: usize = 80;
+}
+
+impl std::fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (name, err) = match self.0 {
+            AocError::Puzzle { day, part: Some(part), source } => (format!("Day{day}.{part}"), source.as_ref()),
+            AocError::Puzzle { day, part: None, source } => (format!("Day{day}"), source.as_ref()),
+            err => (String::new(), err),
+        };
+
+        let AocError::Parse { line: Some(line), column, text, snippet: Some(snippet), reason, .. } = err else {
+            return match name.as_str() {
+                "" => write!(f, "error: {err:#}"),
+                name => write!(f, "error[{name}]: {err:#}"),
+            };
+        };
+
+        match name.as_str() {
+            "" => writeln!(f, "error: {reason}")?,
+            name => writeln!(f, "error[{name}]: {reason}")?,
+        }
+
+        let chars = snippet.chars().collect::<Vec<_>>();
+        let indent = chars.iter().take_while(|c| c.is_whitespace()).count();
+        let start = column.map(|c| c.saturating_sub(1)).unwrap_or(indent).min(chars.len());
+        let width = match (column, text.chars().count()) {
+            (None, _) => chars.len().saturating_sub(start).max(1),
+            (_, 0) => 1,
+            (_, width) => width,
+        };
+
+        // Very long lines (a whole puzzle on one line is common) are trimmed to a window around the error.
+        let (window_start, window_end) = if chars.len() <= Self::MAX_WIDTH {
+            (0, chars.len())
+        } else {
+            let window_start = start.saturating_sub(Self::MAX_WIDTH / 2).min(chars.len() - Self::MAX_WIDTH);
+            (window_start, window_start + Self::MAX_WIDTH)
+        };
+        let width = width.min(window_end.saturating_sub(start)).max(1);
+        let prefix = if window_start > 0 { "..." } else { "" };
+        let suffix = if window_end < chars.len() { "..." } else { "" };
+
+        let gutter = " ".repeat(line.to_string().len());
+        match column {
+            Some(column) => writeln!(f, "{gutter}--> line {line}, column {column}")?,
+            None => writeln!(f, "{gutter}--> line {line}")?,
+        }
+
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {prefix}{}{suffix}", chars[window_start..window_end].iter().collect::<String>())?;
+
+        let padding = chars[window_start..start].iter().map(|c| if *c == '\t' { '\t' } else { ' ' }).collect::<String>();
+        write!(f, "{gutter} | {}{padding}{}", " ".repeat(prefix.len()), "^".repeat(width))?;
+
+        if !text.is_empty() && column.is_none() {
+            write!(f, " found '{text}'")?;
+        }
+
+        let mut cause = std::error::Error::source(err);
+        while let Some(err) = cause {
+            write!(f, "\n{gutter} = caused by: {err}")?;
+            cause = err.source();
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds location information to the errors produced while parsing a line of input.
+pub trait ParseContext<T> {
+    fn on_line(self, number: usize) -> Result<T, AocError>;
+}
+
+impl<T> ParseContext<T> for Result<T, AocError> {
+    fn on_line(self, number: usize) -> Result<T, AocError> {
+        self.map_err(|err| err.on_line(number))
+    }
+}
+
+/// Parses `part` of an input `line` into a value, reporting where it was found if it isn't valid.
+pub fn parse_in<T>(line: &str, part: &str, reason: &str) -> Result<T, AocError>
+    where T: std::str::FromStr,
+          T::Err: Error + Send + Sync + 'static
+{
+    part.trim().parse().map_err(|err| AocError::parse_in(line, part.trim(), reason).caused_by(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_in() {
+        let line = "47|5x";
+        let err = parse_in::<u32>(line, &line[3..], "expected a page number").on_line(12).unwrap_err();
+
+        assert_eq!(err.location(), (Some(12), Some(4)));
+        assert_eq!(err.to_string(), "expected a page number (found '5x') at line 12, column 4");
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn test_in_puzzle() {
+        let err = AocError::parse("?", "unexpected character").at(3, 7).in_puzzle(5, None);
+
+        assert_eq!(err.location(), (Some(3), Some(7)));
+        assert_eq!(err.to_string(), "Day5: unexpected character (found '?') at line 3, column 7");
+        assert_eq!(err.in_puzzle(6, Some(1)).to_string(), "Day5: unexpected character (found '?') at line 3, column 7");
+
+        let err = AocError::from("abc".parse::<u32>().unwrap_err()).in_puzzle(1, Some(2));
+        assert_eq!(format!("{err:#}"), "Day1.2: expected a number\n  caused by: invalid digit found in string");
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let line = "75-29";
+        let err = AocError::parse_in(line, line, "expected '|' in page ordering rule").on_line(5).in_puzzle(5, None);
+
+        assert_eq!(err.diagnostic().to_string(), [
+            "error[Day5]: expected '|' in page ordering rule",
+            " --> line 5, column 1",
+            "  |",
+            "5 | 75-29",
+            "  | ^^^^^",
+        ].join("\n"));
+
+        let line = "  161011: 16 1x 13";
+        let err = parse_in::<u64>(line, &line[13..15], "expected a number").on_line(12).unwrap_err().in_puzzle(7, Some(2));
+
+        assert_eq!(err.diagnostic().to_string(), [
+            "error[Day7.2]: expected a number",
+            "  --> line 12, column 14",
+            "   |",
+            "12 |   161011: 16 1x 13",
+            "   |              ^^",
+            "   = caused by: invalid digit found in string",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn test_diagnostic_long_line() {
+        let line = format!("{}mul(1,?){}", "x".repeat(200), "y".repeat(200));
+        let err = AocError::parse_in(&line, &line[206..207], "expected a number").on_line(1);
+        let rendered = err.diagnostic().to_string();
+        let lines = rendered.lines().collect::<Vec<_>>();
+
+        assert!(lines[3].starts_with("1 | ...") && lines[3].ends_with("..."));
+        assert_eq!(lines[3].find("?"), lines[4].find("^"));
+    }
+
+    #[test]
+    fn test_diagnostic_without_location() {
+        let err = AocError::solve("No start found").in_puzzle(6, Some(1));
+        assert_eq!(err.diagnostic().to_string(), "error[Day6.1]: No start found");
+    }
+
+    #[test]
+    fn test_cause_chain() {
+        let err = AocError::from("abc".parse::<u32>().unwrap_err());
+        assert_eq!(format!("{err:#}"), "expected a number\n  caused by: invalid digit found in string");
+    }
+}
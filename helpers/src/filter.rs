@@ -0,0 +1,180 @@
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::error::AocError;
+
+/// Which kinds of input to run each part against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Only {
+    #[default]
+    Both,
+    Examples,
+    Real,
+}
+
+impl FromStr for Only {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" | "both" | "all" => Ok(Only::Both),
+            "examples" | "example" => Ok(Only::Examples),
+            "real" | "input" => Ok(Only::Real),
+            other => Err(AocError::parse(other, "expected one of examples, real or both")),
+        }
+    }
+}
+
+/// Narrows down which puzzles are run, so that a single day (or part, or kind of input) can be checked
+/// without running everything else.
+///
+/// The generated tests and the `solutions` harness read this from the environment (see
+/// [`Filter::from_env`]), while the runner builds it from its command line options.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::filter::{Filter, Only};
+///
+/// let filter = Filter { days: "3-7".parse().unwrap(), part: Some(2), only: Only::Real, ..Filter::default() };
+/// assert!(filter.includes(5, 2));
+/// assert!(!filter.includes(5, 1));
+/// assert!(!filter.includes(8, 2));
+/// assert!(filter.real() && !filter.examples());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    pub days: Days,
+    pub part: Option<u8>,
+    pub only: Only,
+    /// Whether to run the real input for days in the slow tier (see [`Tier::Slow`](crate::questions::Tier::Slow)).
+    pub include_slow: bool,
+}
+
+impl Filter {
+    /// Reads the filter from `AOC_DAYS` (a day like `5` or a range like `3-7`), `AOC_PART` (`1` or `2`),
+    /// `AOC_ONLY` (`examples` or `real`) and `AOC_SLOW` (`1` to include slow days), any of which can be
+    /// left unset.
+    pub fn from_env() -> Result<Self, AocError> {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.trim().is_empty());
+
+        let part = match var("AOC_PART") {
+            Some(part) => match crate::error::parse_in(&part, &part, "expected a part number")? {
+                part @ (1 | 2) => Some(part),
+                _ => return Err(AocError::parse(part, "expected AOC_PART to be 1 or 2")),
+            },
+            None => None,
+        };
+
+        Ok(Self {
+            days: var("AOC_DAYS").map(|days| days.parse()).transpose()?.unwrap_or_default(),
+            part,
+            only: var("AOC_ONLY").map(|only| only.parse()).transpose()?.unwrap_or_default(),
+            include_slow: var("AOC_SLOW").is_some_and(|slow| !matches!(slow.trim(), "0" | "false" | "no")),
+        })
+    }
+
+    /// Whether the given day and part should be run at all.
+    pub fn includes(&self, day: u8, part: u8) -> bool {
+        self.days.0.contains(&day) && self.part.is_none_or(|p| p == part)
+    }
+
+    pub fn examples(&self) -> bool {
+        self.only != Only::Real
+    }
+
+    pub fn real(&self) -> bool {
+        self.only != Only::Examples
+    }
+
+    pub fn is_everything(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Display for Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if self.days != Days::default() {
+            parts.push(self.days.to_string());
+        }
+
+        if let Some(part) = self.part {
+            parts.push(format!("part {part}"));
+        }
+
+        match self.only {
+            Only::Both => {},
+            Only::Examples => parts.push("examples only".to_string()),
+            Only::Real => parts.push("real inputs only".to_string()),
+        }
+
+        if self.include_slow {
+            parts.push("including slow days".to_string());
+        }
+
+        match parts.is_empty() {
+            true => write!(f, "everything"),
+            false => write!(f, "{}", parts.join(", ")),
+        }
+    }
+}
+
+/// An inclusive range of days, written as a single day (`5`) or a range (`3-7`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Days(pub RangeInclusive<u8>);
+
+impl Default for Days {
+    fn default() -> Self {
+        Self(1..=25)
+    }
+}
+
+impl FromStr for Days {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (start, end) = s.split_once('-').unwrap_or((s, s));
+        let start: u8 = crate::error::parse_in(s, start.trim(), "expected a day number")?;
+        let end: u8 = crate::error::parse_in(s, end.trim(), "expected a day number")?;
+
+        if start > end {
+            return Err(AocError::parse(s, "expected the first day of the range to come before the last"));
+        }
+
+        Ok(Self(start..=end))
+    }
+}
+
+impl Display for Days {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.0.start(), self.0.end()) {
+            (start, end) if start == end => write!(f, "day {start}"),
+            (start, end) => write!(f, "days {start}-{end}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("5".parse::<Days>().unwrap(), Days(5..=5));
+        assert_eq!(" 3 - 7 ".parse::<Days>().unwrap(), Days(3..=7));
+        assert!("7-3".parse::<Days>().is_err());
+        assert!("five".parse::<Days>().is_err());
+
+        assert_eq!("examples".parse::<Only>().unwrap(), Only::Examples);
+        assert!("some".parse::<Only>().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Filter::default().to_string(), "everything");
+        assert_eq!(Filter { days: Days(5..=5), part: Some(2), only: Only::Real, include_slow: true }.to_string(), "day 5, part 2, real inputs only, including slow days");
+        assert_eq!(Filter { days: Days(3..=7), ..Filter::default() }.to_string(), "days 3-7");
+    }
+}
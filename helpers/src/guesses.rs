@@ -0,0 +1,227 @@
+use std::fmt::Display;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{parse_in, AocError, ParseContext};
+use crate::questions::Answer;
+
+/// What the puzzle said about an answer we gave it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer was wrong, without any hint about which way it was off.
+    Incorrect,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    pub fn is_wrong(&self) -> bool {
+        *self != Verdict::Correct
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.pad("correct"),
+            Verdict::Incorrect => f.pad("incorrect"),
+            Verdict::TooHigh => f.pad("too high"),
+            Verdict::TooLow => f.pad("too low"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "correct" => Ok(Verdict::Correct),
+            "incorrect" | "wrong" => Ok(Verdict::Incorrect),
+            "too high" | "too-high" | "high" => Ok(Verdict::TooHigh),
+            "too low" | "too-low" | "low" => Ok(Verdict::TooLow),
+            other => Err(AocError::parse(other, "expected one of correct, incorrect, too-high or too-low")),
+        }
+    }
+}
+
+/// An answer which was given for a puzzle, along with the verdict it received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Display for Guess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day{}.{}\t{}\t{}", self.day, self.part, self.verdict, self.answer.replace('\n', "\\n"))
+    }
+}
+
+impl FromStr for Guess {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.splitn(3, '\t').collect();
+        let [name, verdict, answer] = fields[..] else {
+            return Err(AocError::parse_in(line, line, "expected 3 tab-separated fields"));
+        };
+
+        let (day, part) = name.strip_prefix("Day").and_then(|name| name.split_once('.'))
+            .ok_or_else(|| AocError::parse_in(line, name, "expected a puzzle like 'Day5.1'"))?;
+
+        Ok(Self {
+            day: parse_in(line, day, "expected a day number")?,
+            part: parse_in(line, part, "expected a part number")?,
+            answer: answer.replace("\\n", "\n"),
+            verdict: verdict.parse().map_err(|_| AocError::parse_in(line, verdict, "expected a verdict"))?,
+        })
+    }
+}
+
+/// A reason to doubt an answer before giving it, based on the guesses which have already been made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// The same answer has already been given and was wrong.
+    Repeated { verdict: Verdict },
+    /// The answer is at least as high as one which was already too high.
+    TooHigh { bound: String },
+    /// The answer is no higher than one which was already too low.
+    TooLow { bound: String },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::Repeated { verdict } => write!(f, "this answer was already rejected ({verdict})"),
+            Warning::TooHigh { bound } => write!(f, "this answer is too high, since {bound} already was"),
+            Warning::TooLow { bound } => write!(f, "this answer is too low, since {bound} already was"),
+        }
+    }
+}
+
+/// A local log of the answers which have been given for each part of a year's puzzles, stored alongside
+/// its inputs (in `inputs/<year>/guesses.tsv`).
+#[derive(Debug, Clone, Default)]
+pub struct GuessLog {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    /// The location of the guess log for the given year, within the inputs directory.
+    pub fn path_for<P: AsRef<Path>>(inputs: P, year: u16) -> PathBuf {
+        inputs.as_ref().join(year.to_string()).join("guesses.tsv")
+    }
+
+    /// Loads the log from the given file, which is treated as empty if it doesn't exist yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, AocError> {
+        let path = path.as_ref().to_path_buf();
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(AocError::io(&path, err)),
+        };
+
+        let guesses = content.lines().enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| line.parse().on_line(i + 1))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { path, guesses })
+    }
+
+    /// The guesses which have been made for a part, in the order they were made.
+    pub fn guesses(&self, day: u8, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses.iter().filter(move |guess| guess.day == day && guess.part == part)
+    }
+
+    /// The highest answer known to be too low and the lowest answer known to be too high for a part.
+    pub fn bounds(&self, day: u8, part: u8) -> (Option<i128>, Option<i128>) {
+        let numbers = |verdict: Verdict| self.guesses(day, part)
+            .filter(move |guess| guess.verdict == verdict)
+            .filter_map(|guess| guess.answer.trim().parse::<i128>().ok());
+
+        (numbers(Verdict::TooLow).max(), numbers(Verdict::TooHigh).min())
+    }
+
+    /// Checks an answer against the previous guesses for its part, returning the reasons to doubt it.
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Vec<Warning> {
+        let mut warnings: Vec<Warning> = self.guesses(day, part)
+            .filter(|guess| guess.verdict.is_wrong() && answer == &guess.answer)
+            .map(|guess| Warning::Repeated { verdict: guess.verdict })
+            .take(1)
+            .collect();
+
+        if let Some(value) = answer.as_integer() {
+            match self.bounds(day, part) {
+                (Some(low), _) if value <= low => warnings.push(Warning::TooLow { bound: low.to_string() }),
+                (_, Some(high)) if value >= high => warnings.push(Warning::TooHigh { bound: high.to_string() }),
+                _ => {},
+            }
+        }
+
+        warnings
+    }
+
+    /// Adds a guess to the log, appending it to the file.
+    pub fn record(&mut self, day: u8, part: u8, answer: &Answer, verdict: Verdict) -> Result<(), AocError> {
+        let guess = Guess { day, part, answer: answer.to_string().trim_end().to_string(), verdict };
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| AocError::io(parent, err))?;
+        }
+
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&self.path)
+            .map_err(|err| AocError::io(&self.path, err))?;
+        writeln!(file, "{guess}").map_err(|err| AocError::io(&self.path, err))?;
+
+        self.guesses.push(guess);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess() {
+        let guess: Guess = "Day5.1\ttoo high\t5000".parse().unwrap();
+        assert_eq!(guess, Guess { day: 5, part: 1, answer: "5000".to_string(), verdict: Verdict::TooHigh });
+        assert_eq!(guess.to_string(), "Day5.1\ttoo high\t5000");
+
+        assert!("Day5.1\ttoo hot\t5000".parse::<Guess>().is_err());
+        assert!("Day5.1 too high 5000".parse::<Guess>().is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let path = std::env::temp_dir().join(format!("aoc-guesses-{}", std::process::id())).join("2024").join("guesses.tsv");
+
+        let mut log = GuessLog::load(&path).unwrap();
+        log.record(5, 1, &Answer::from(5000), Verdict::TooHigh).unwrap();
+        log.record(5, 1, &Answer::from(4000), Verdict::TooLow).unwrap();
+        log.record(5, 1, &Answer::from(4500), Verdict::Incorrect).unwrap();
+        log.record(5, 1, &Answer::from(6000), Verdict::TooHigh).unwrap();
+
+        let log = GuessLog::load(&path).unwrap();
+        std::fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+
+        assert_eq!(log.bounds(5, 1), (Some(4000), Some(5000)));
+        assert_eq!(log.bounds(5, 2), (None, None));
+
+        assert!(log.check(5, 1, &Answer::from(4321)).is_empty());
+        assert!(log.check(5, 2, &Answer::from(5000)).is_empty());
+        assert_eq!(log.check(5, 1, &Answer::from(4500)), vec![Warning::Repeated { verdict: Verdict::Incorrect }]);
+        assert_eq!(log.check(5, 1, &Answer::from(3999)), vec![Warning::TooLow { bound: "4000".to_string() }]);
+        assert_eq!(log.check(5, 1, &Answer::from(5000)), vec![
+            Warning::Repeated { verdict: Verdict::TooHigh },
+            Warning::TooHigh { bound: "5000".to_string() },
+        ]);
+    }
+}
@@ -0,0 +1,209 @@
+use std::any::Any;
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::answers::KnownAnswers;
+use crate::error::AocError;
+use crate::filter::Filter;
+use crate::inputs::InputStore;
+use crate::parallel::map_reduce;
+use crate::questions::{Example, Solution, Tier, Year};
+use crate::results::{self, PuzzleResult, ResultContainer, Status};
+
+/// Runs a day's parts against one of its inputs.
+type Job = Box<dyn FnOnce() -> Vec<PuzzleResult> + Send>;
+
+/// The entry point for each year's `harness = false` test target, which runs every registered day's
+/// examples and real input (checking the latter against the year's known answers), prints a single table
+/// of the results, and fails if any of them need fixing. Set `AOC_DAYS`, `AOC_PART` or `AOC_ONLY` to run
+/// only some of them (see [`Filter::from_env`]).
+///
+/// # Examples
+/// ```ignore
+/// fn main() -> std::process::ExitCode {
+///     aoc_helpers::harness::main(&aoc2024::YEAR, concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs"))
+/// }
+/// ```
+pub fn main<P: AsRef<Path>>(year: &'static Year, inputs: P) -> ExitCode {
+    let filter = match Filter::from_env() {
+        Ok(filter) => filter,
+        Err(err) => {
+            eprintln!("{}", err.diagnostic());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let results = match run(year, inputs.as_ref(), &filter) {
+        Ok(results) => results,
+        Err(err) => {
+            eprintln!("{}", err.diagnostic());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!();
+    match filter.is_everything() {
+        true => println!("Results for {}:", year.year),
+        false => println!("Results for {} ({filter}):", year.year),
+    }
+    println!("{results}");
+
+    if results.failures() == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Runs the examples and real input for each of the year's solutions (spreading the work across several
+/// threads) and collects the results in a deterministic order: by day, then part, with the examples for a
+/// part ahead of its real input.
+///
+/// Inputs are resolved through an [`InputStore`] for the directory, so they are downloaded (one at a time)
+/// if a session has been set, and otherwise reported as missing without failing the run. A session which
+/// can't be used (see [`InputStore::from_env`]) fails the run up front.
+pub fn run(year: &'static Year, inputs: &Path, filter: &Filter) -> Result<ResultContainer, AocError> {
+    run_solutions(year.year, year.solutions, inputs, filter)
+}
+
+/// Runs the examples and real input for some of a year's solutions, in the same way as [`run`]. Only the
+/// puzzles which the filter lets through are run (and reported).
+pub fn run_solutions(year: u16, solutions: &'static [Solution], inputs: &Path, filter: &Filter) -> Result<ResultContainer, AocError> {
+    let store = InputStore::from_env(inputs)?;
+    let answers = match KnownAnswers::load(KnownAnswers::path_for(inputs, year)) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Not checking the known answers for {year}: {}", err.diagnostic());
+            KnownAnswers::default()
+        }
+    };
+
+    let mut queue: Vec<((usize, usize), Job)> = Vec::new();
+    for (i, solution) in solutions.iter().enumerate() {
+        let parts: Vec<u8> = [1, 2].into_iter().filter(|&part| filter.includes(solution.day, part)).collect();
+        if !parts.is_empty() {
+            queue.extend(jobs(solution, &parts, filter, &store, &answers).into_iter().enumerate().map(|(k, job)| ((i, k), job)));
+        }
+    }
+
+    let mut results = map_reduce(queue.into_iter(), |(key, job)| (key, job()), Vec::new(), |mut results, (key, batch)| {
+        results.extend(batch.into_iter().map(|result| (key, result)));
+        results
+    });
+
+    // Each job covers one of a day's inputs, but the results are listed by day, then part, with the examples
+    // for a part ahead of its real input.
+    results.sort_by_key(|((solution, input), result)| (*solution, result.part(), *input));
+    Ok(results.into_iter().map(|(_, result)| result).collect())
+}
+
+/// Runs each of a solution's examples for a part, printing their results, and fails naming every example
+/// which didn't produce its expected answer.
+pub fn check_examples(solution: &Solution, part: u8, inputs: &Path) -> Result<(), AocError> {
+    let store = InputStore::new(inputs);
+    let mut failures = Vec::new();
+
+    for (i, example) in solution.examples.iter().enumerate() {
+        let Some(expected) = example.answer(part) else {
+            continue;
+        };
+
+        let result = match solution.example_input(example, &store) {
+            Ok(input) => PuzzleResult::measure_parsed(solution.example_name(i, part),
+                || (solution.parse)(input.trim()),
+                |parsed| solution.part(part)(parsed.as_ref(), example.params())).0,
+            Err(err) => PuzzleResult::failed(solution.example_name(i, part), &err),
+        }.expecting(expected);
+        println!("{result}");
+
+        match (result.status(), result.answer(), result.error()) {
+            (Status::WrongAnswer, Some(answer), _) => failures.push(format!("'{}' produced {answer} instead of {expected}", example.label(i))),
+            (status, _, Some(err)) if status.is_failure() => failures.push(format!("'{}' failed: {err}", example.label(i))),
+            _ => {},
+        }
+    }
+
+    match failures.len() {
+        0 => Ok(()),
+        _ => Err(AocError::solve(failures.join("; ")).in_puzzle(solution.day, Some(part))),
+    }
+}
+
+/// Parses an input once and then runs each of the given parts (named for reporting) against it, each on
+/// its own thread within the solution's time budget. An example's parameters are used if one is given.
+///
+/// Since the input is only parsed once, the parse time is counted against the first part alone, so that
+/// adding up the results doesn't count it twice.
+pub fn run_parts<I>(solution: &'static Solution, input: Result<I, Arc<AocError>>, parts: Vec<(u8, String)>, example: Option<&'static Example>) -> Vec<PuzzleResult>
+    where I: AsRef<str> + Send + 'static
+{
+    let timeout = solution.timeout.unwrap_or_else(results::timeout);
+    let parsed = input.and_then(|input| results::parse_with_timeout(solution.name, timeout, move || (solution.parse)(input.as_ref().trim()))
+        .map(|(parsed, duration)| (Arc::<dyn Any + Send + Sync>::from(parsed), duration))
+        .map_err(Arc::new));
+
+    parts.into_iter().enumerate().map(|(i, (part, name))| {
+        let result = match &parsed {
+            Ok((parsed, parse_duration)) => {
+                let (result, _) = PuzzleResult::measure_parsed_with_timeout(name, timeout, Arc::clone(parsed),
+                    move |parsed| solution.part(part)(parsed, example.and_then(Example::params)));
+                result.with_parse_duration(if i == 0 { *parse_duration } else { Duration::ZERO })
+            },
+            Err(err) => PuzzleResult::failed(name, err),
+        };
+
+        result.for_part(solution.day, part)
+    }).collect()
+}
+
+/// The jobs for each of a solution's inputs: first its examples, then its real input. Each job parses its
+/// input once and runs all of the given parts which apply to it.
+fn jobs(solution: &'static Solution, parts: &[u8], filter: &Filter, store: &InputStore, answers: &KnownAnswers) -> Vec<Job> {
+    let names = |parts: &[u8]| parts.iter().map(|&part| (part, format!("{}.{part}", solution.name))).collect::<Vec<_>>();
+
+    if let Tier::Skip(reason) = solution.tier {
+        let results = names(parts).into_iter().map(|(part, name)| PuzzleResult::skipped(name).because(reason).for_part(solution.day, part)).collect();
+        return vec![Box::new(move || results)];
+    }
+
+    let mut jobs: Vec<Job> = Vec::new();
+    let examples = if filter.examples() { solution.examples } else { &[] };
+    for (i, example) in examples.iter().enumerate() {
+        let expected: Vec<(u8, &str)> = parts.iter().filter_map(|&part| example.answer(part).map(|answer| (part, answer))).collect();
+        if expected.is_empty() {
+            continue;
+        }
+
+        let names = expected.iter().map(|&(part, _)| (part, solution.example_name(i, part))).collect();
+        let input = solution.example_input(example, store).map_err(Arc::new);
+        jobs.push(Box::new(move || run_parts(solution, input, names, Some(example)).into_iter().zip(expected)
+            .map(|(result, (_, expected))| result.expecting(expected))
+            .collect()));
+    }
+
+    if !filter.real() {
+        return jobs;
+    }
+
+    match solution.tier {
+        Tier::Slow(reason) if !filter.include_slow => {
+            let results = names(parts).into_iter().map(|(part, name)| PuzzleResult::slow(name).because(reason).for_part(solution.day, part)).collect();
+            jobs.push(Box::new(move || results));
+        },
+        _ => {
+            let input = store.get(solution.year, solution.day).map(Arc::<str>::from).map_err(Arc::new);
+            let known: Vec<Option<String>> = parts.iter().map(|&part| answers.get(solution.day, part).map(str::to_string)).collect();
+            let names = names(parts);
+            jobs.push(Box::new(move || run_parts(solution, input, names, None).into_iter().zip(known)
+                .map(|(result, known)| match known {
+                    Some(known) => result.expecting(&known),
+                    None => result,
+                })
+                .collect()));
+        },
+    }
+
+    jobs
+}
@@ -0,0 +1,302 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+use crate::error::AocError;
+
+/// How long to wait on the network before giving up on a request.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A minimal HTTP/1.1 client built on [`TcpStream`], which is all we need to talk to the puzzle site
+/// without pulling in any dependencies.
+///
+/// The standard library has no TLS support, so only `http://` URLs can be used. To reach a site which is
+/// only served over HTTPS, point the client at a local TLS-terminating proxy instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+/// The status, headers and body returned by the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn get<U: ToString>(url: U) -> Self {
+        Self { method: "GET".to_string(), url: url.to_string(), headers: Vec::new(), body: String::new() }
+    }
+
+    /// A POST of a URL-encoded form, such as the one used to submit answers.
+    pub fn post_form<U: ToString>(url: U, fields: &[(&str, &str)]) -> Self {
+        let body = fields.iter()
+            .map(|(key, value)| format!("{}={}", url_encode(key), url_encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        Self { method: "POST".to_string(), url: url.to_string(), headers: Vec::new(), body }
+            .header("Content-Type", "application/x-www-form-urlencoded")
+    }
+
+    pub fn header<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sends the request and waits for the full response.
+    pub fn send(&self) -> Result<Response, AocError> {
+        let (host, port, path) = parse_url(&self.url)?;
+        let io = |err| AocError::solve(format!("request to {} failed", self.url)).caused_by(err);
+
+        let mut stream = TcpStream::connect((host, port)).map_err(io)?;
+        stream.set_read_timeout(Some(TIMEOUT)).map_err(io)?;
+        stream.set_write_timeout(Some(TIMEOUT)).map_err(io)?;
+
+        let mut request = format!("{} {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\nUser-Agent: aoc-helpers (std-only)\r\n", self.method);
+        for (key, value) in self.headers.iter() {
+            request.push_str(&format!("{key}: {value}\r\n"));
+        }
+
+        if !self.body.is_empty() || self.method == "POST" {
+            request.push_str(&format!("Content-Length: {}\r\n", self.body.len()));
+        }
+
+        request.push_str("\r\n");
+        request.push_str(&self.body);
+        stream.write_all(request.as_bytes()).map_err(io)?;
+
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).map_err(io)?;
+
+        Response::parse(&raw).map_err(|err| AocError::solve(format!("could not understand the response from {}", self.url)).caused_by(err))
+    }
+}
+
+impl Response {
+    pub fn new<B: ToString>(status: u16, body: B) -> Self {
+        Self { status, headers: Vec::new(), body: body.to_string() }
+    }
+
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers.iter().find(|(k, _)| k.eq_ignore_ascii_case(key)).map(|(_, v)| v.as_str())
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    fn parse(raw: &[u8]) -> Result<Self, AocError> {
+        let split = raw.windows(4).position(|w| w == b"\r\n\r\n")
+            .ok_or_else(|| AocError::parse("", "expected the end of the response headers"))?;
+        let head = String::from_utf8_lossy(&raw[..split]);
+        let body = &raw[split + 4..];
+
+        let mut lines = head.lines();
+        let status_line = lines.next().unwrap_or_default();
+        let status = status_line.split_whitespace().nth(1)
+            .ok_or_else(|| AocError::parse(status_line, "expected an HTTP status line"))?
+            .parse()
+            .map_err(|err| AocError::parse(status_line, "expected an HTTP status code").caused_by(err))?;
+
+        let headers = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect();
+
+        let mut response = Self { status, headers, body: String::new() };
+        response.body = match response.header("Transfer-Encoding") {
+            Some(encoding) if encoding.eq_ignore_ascii_case("chunked") => String::from_utf8_lossy(&dechunk(body)?).into_owned(),
+            _ => String::from_utf8_lossy(body).into_owned(),
+        };
+
+        Ok(response)
+    }
+}
+
+impl std::fmt::Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n", self.status, reason(self.status), self.body.len())?;
+        for (key, value) in self.headers.iter() {
+            write!(f, "{key}: {value}\r\n")?;
+        }
+
+        write!(f, "\r\n{}", self.body)
+    }
+}
+
+/// A local stand-in for a real server, which answers each request on a background thread using the
+/// given handler. This lets the clients built on [`Request`] be tested without any network access.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::http::{Request, Response, StandIn};
+///
+/// let server = StandIn::start(|request| Response::new(200, format!("you asked for {}", request.url))).unwrap();
+///
+/// let response = Request::get(server.url("/2024/day/1/input")).send().unwrap();
+/// assert_eq!(response.body, "you asked for /2024/day/1/input");
+/// ```
+pub struct StandIn {
+    address: std::net::SocketAddr,
+}
+
+impl StandIn {
+    /// Starts the server on a free local port. The handler receives each request with its `url` set to
+    /// the path which was requested.
+    pub fn start<F>(handler: F) -> Result<Self, AocError>
+        where F: Fn(Request) -> Response + Send + 'static
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").map_err(|err| AocError::solve("could not start the stand-in server").caused_by(err))?;
+        let address = listener.local_addr().map_err(|err| AocError::solve("could not start the stand-in server").caused_by(err))?;
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if let Some(request) = read_request(&stream) {
+                    let _ = (&stream).write_all(handler(request).to_string().as_bytes());
+                }
+            }
+        });
+
+        Ok(Self { address })
+    }
+
+    /// The full URL of the given path on this server.
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{path}", self.address)
+    }
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let (method, url) = (parts.next()?.to_string(), parts.next()?.to_string());
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        match line.trim_end().split_once(':') {
+            Some((key, value)) => headers.push((key.trim().to_string(), value.trim().to_string())),
+            None => break,
+        }
+    }
+
+    let length = headers.iter()
+        .find(|(key, _): &&(String, String)| key.eq_ignore_ascii_case("Content-Length"))
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(0);
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(Request { method, url, headers, body: String::from_utf8_lossy(&body).into_owned() })
+}
+
+/// Splits an `http://host[:port]/path` URL into its parts.
+fn parse_url(url: &str) -> Result<(&str, u16, &str), AocError> {
+    if url.starts_with("https://") {
+        return Err(AocError::solve(format!("cannot request {url}: HTTPS is not supported by the built-in client, so use a local TLS proxy and an http:// URL instead")));
+    }
+
+    let rest = url.strip_prefix("http://")
+        .ok_or_else(|| AocError::parse(url, "expected an http:// URL"))?;
+    let (authority, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+
+    match authority.rsplit_once(':') {
+        Some((host, port)) => Ok((host, port.parse().map_err(|err| AocError::parse(port, "expected a port number").caused_by(err))?, path)),
+        None => Ok((authority, 80, path)),
+    }
+}
+
+fn dechunk(mut body: &[u8]) -> Result<Vec<u8>, AocError> {
+    let mut out = Vec::new();
+    loop {
+        let end = body.windows(2).position(|w| w == b"\r\n")
+            .ok_or_else(|| AocError::parse("", "expected a chunk size"))?;
+        let size = String::from_utf8_lossy(&body[..end]);
+        let size = usize::from_str_radix(size.split(';').next().unwrap_or_default().trim(), 16)
+            .map_err(|err| AocError::parse(size.trim(), "expected a hexadecimal chunk size").caused_by(err))?;
+
+        if size == 0 {
+            return Ok(out);
+        }
+
+        let chunk = body.get(end + 2..end + 2 + size)
+            .ok_or_else(|| AocError::parse("", "the response ended part way through a chunk"))?;
+        out.extend_from_slice(chunk);
+        body = body.get(end + 4 + size..).unwrap_or_default();
+    }
+}
+
+fn url_encode(value: &str) -> String {
+    value.bytes().map(|b| match b {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+        b' ' => "+".to_string(),
+        b => format!("%{b:02X}"),
+    }).collect()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        302 => "Found",
+        400 => "Bad Request",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(parse_url("http://localhost:8080/2024/day/1").unwrap(), ("localhost", 8080, "/2024/day/1"));
+        assert_eq!(parse_url("http://example.com").unwrap(), ("example.com", 80, "/"));
+        assert!(parse_url("https://adventofcode.com/").unwrap_err().to_string().contains("TLS proxy"));
+        assert!(parse_url("ftp://example.com/").is_err());
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let response = Response::parse(b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\n1 2 3\n").unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.header("content-type"), Some("text/plain"));
+        assert_eq!(response.body, "1 2 3\n");
+
+        let response = Response::parse(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n1 2 \r\n2\r\n3\n\r\n0\r\n\r\n").unwrap();
+        assert_eq!(response.body, "1 2 3\n");
+
+        assert!(Response::parse(b"HTTP/1.1 200 OK\r\n").is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let server = StandIn::start(|request| {
+            let cookie = request.headers.iter().find(|(key, _)| key == "Cookie").map(|(_, value)| value.clone()).unwrap_or_default();
+            Response::new(200, format!("{} {} [{cookie}] {}", request.method, request.url, request.body))
+        }).unwrap();
+
+        let response = Request::post_form(server.url("/2024/day/1/answer"), &[("level", "1"), ("answer", "a b&c")])
+            .header("Cookie", "session=abc")
+            .send()
+            .unwrap();
+
+        assert!(response.is_success());
+        assert_eq!(response.body, "POST /2024/day/1/answer [session=abc] level=1&answer=a+b%26c");
+    }
+}
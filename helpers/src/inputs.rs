@@ -0,0 +1,223 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::AocError;
+use crate::http::{Request, Response};
+
+/// Somewhere puzzle inputs can be fetched from when they haven't been cached yet.
+///
+/// Any `Fn(year, day) -> Result<String, AocError>` closure can be used as a backend, which is handy for
+/// tests.
+pub trait Fetch: Send + Sync {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, AocError>;
+}
+
+impl<F> Fetch for F
+    where F: Fn(u16, u8) -> Result<String, AocError> + Send + Sync
+{
+    fn fetch(&self, year: u16, day: u8) -> Result<String, AocError> {
+        self(year, day)
+    }
+}
+
+/// The puzzle site (or a local stand-in for it), along with the session cookie used to log in.
+///
+/// The built-in client only speaks plain HTTP (see [`Request`]), so `AOC_URL` must point at a local
+/// TLS-terminating proxy in order to reach the real site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Http {
+    base: String,
+    session: String,
+}
+
+impl Http {
+    /// The puzzle site, which can only be reached through a TLS proxy given in `AOC_URL`.
+    pub const SITE: &'static str = "https://adventofcode.com";
+
+    pub fn new<U: ToString, S: ToString>(base: U, session: S) -> Self {
+        Self { base: base.to_string().trim_end_matches('/').to_string(), session: session.to_string().trim().to_string() }
+    }
+
+    /// Reads the session cookie from `AOC_SESSION` and the site from `AOC_URL`, or `None` if no session has
+    /// been set. Since the site itself needs HTTPS, a session without an `http://` `AOC_URL` (pointing at a
+    /// local TLS proxy) is an error rather than a client which could never connect.
+    pub fn from_env() -> Result<Option<Self>, AocError> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Like [`Http::from_env`], but looks the variables up with `var` rather than in the process's environment.
+    pub fn from_vars<V: Fn(&str) -> Option<String>>(var: V) -> Result<Option<Self>, AocError> {
+        let var = |name: &str| var(name).filter(|value| !value.trim().is_empty());
+        let Some(session) = var("AOC_SESSION") else {
+            return Ok(None);
+        };
+
+        match var("AOC_URL") {
+            Some(base) if base.trim().starts_with("http://") => Ok(Some(Self::new(base.trim(), session))),
+            Some(base) => Err(AocError::solve(format!("AOC_URL is set to '{base}', but the built-in client only speaks plain HTTP: set it to the http:// address of a local TLS proxy for {}", Self::SITE))),
+            None => Err(AocError::solve(format!("AOC_SESSION is set, but the built-in client can't reach {} directly: set AOC_URL to the http:// address of a local TLS proxy for it", Self::SITE))),
+        }
+    }
+
+    /// Sends a request for a path on the site, logged in with the session cookie.
+    pub fn send(&self, request: Request) -> Result<Response, AocError> {
+        request.header("Cookie", format!("session={}", self.session)).send()
+    }
+
+    /// The full URL of a path on the site.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base)
+    }
+}
+
+impl Fetch for Http {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, AocError> {
+        let url = self.url(&format!("/{year}/day/{day}/input"));
+        let response = self.send(Request::get(&url))?;
+
+        match response.status {
+            200 => Ok(response.body),
+            404 => Err(AocError::solve(format!("there is no input at {url} (has the puzzle been unlocked yet?)"))),
+            400 | 500 => Err(AocError::solve(format!("could not download {url}: the session cookie was rejected ({})", response.status))),
+            status => Err(AocError::solve(format!("could not download {url}: the site responded with {status}"))),
+        }
+    }
+}
+
+/// Resolves puzzle inputs at runtime from a cache directory (`inputs/<year>/DayN.txt`), fetching and
+/// caching any which are missing through a pluggable [`Fetch`] backend.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::error::AocError;
+/// use aoc_helpers::inputs::InputStore;
+///
+/// let cache = std::env::temp_dir().join(format!("aoc-inputs-doc-{}", std::process::id()));
+/// let store = InputStore::new(&cache).with_backend(|year, day| Ok::<_, AocError>(format!("{year} {day}\n")));
+///
+/// assert_eq!(store.get(2024, 5).unwrap(), "2024 5\n");
+/// assert!(store.path(2024, 5).exists());
+/// # std::fs::remove_dir_all(cache).unwrap();
+/// ```
+pub struct InputStore {
+    cache: PathBuf,
+    backend: Option<Box<dyn Fetch>>,
+}
+
+impl InputStore {
+    /// A store which only reads inputs which have already been cached.
+    pub fn new<P: AsRef<Path>>(cache: P) -> Self {
+        Self { cache: cache.as_ref().to_path_buf(), backend: None }
+    }
+
+    /// A store which downloads missing inputs from the puzzle site if a session cookie and a proxy for the
+    /// site have been set (see [`Http::from_env`]). Otherwise it only reads inputs which have been cached,
+    /// and reports the rest as missing. A session which can't be used is an error, so that it isn't ignored.
+    pub fn from_env<P: AsRef<Path>>(cache: P) -> Result<Self, AocError> {
+        Ok(match Http::from_env()? {
+            Some(http) => Self::new(cache).with_backend(http),
+            None => Self::new(cache),
+        })
+    }
+
+    pub fn with_backend<F: Fetch + 'static>(self, backend: F) -> Self {
+        Self { backend: Some(Box::new(backend)), ..self }
+    }
+
+    /// Where the input for a day is cached.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache.join(year.to_string()).join(format!("Day{day}.txt"))
+    }
+
+    /// Reads the `k`th example input saved for a day (named like `Day18`) from `examples/Day18-k.txt`.
+    /// Examples are never fetched, since they need to be copied out of the puzzle description.
+    pub fn example(&self, name: &str, k: u8) -> Result<String, AocError> {
+        let path = self.cache.join("examples").join(format!("{name}-{k}.txt"));
+        std::fs::read_to_string(&path).map_err(|err| AocError::io(&path, err))
+    }
+
+    /// Reads the input for a day, fetching it first if it hasn't been cached yet (or has only been cached as
+    /// an empty placeholder). Without a backend, an uncached input is reported as [`AocError::MissingInput`].
+    pub fn get(&self, year: u16, day: u8) -> Result<String, AocError> {
+        let path = self.path(year, day);
+        match std::fs::read_to_string(&path) {
+            // An empty file is a placeholder for an input which hasn't been downloaded yet.
+            Ok(input) if !input.trim().is_empty() => return Ok(input),
+            Ok(_) => {},
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(AocError::io(&path, err)),
+            Err(_) => {},
+        }
+
+        let Some(backend) = self.backend.as_ref() else {
+            return Err(AocError::MissingInput { year, day, path: path.display().to_string() });
+        };
+
+        let input = backend.fetch(year, day)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| AocError::io(parent, err))?;
+        }
+
+        std::fs::write(&path, &input).map_err(|err| AocError::io(&path, err))?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::StandIn;
+
+    use super::*;
+
+    #[test]
+    fn test_missing() {
+        let cache = std::env::temp_dir().join(format!("aoc-inputs-missing-{}", std::process::id()));
+        let store = InputStore::new(&cache);
+        assert!(matches!(store.get(2024, 1), Err(AocError::MissingInput { year: 2024, day: 1, .. })));
+
+        std::fs::create_dir_all(cache.join("2024")).unwrap();
+        std::fs::write(store.path(2024, 1), "\n").unwrap();
+        let placeholder = store.get(2024, 1);
+        std::fs::remove_dir_all(&cache).unwrap();
+        assert!(matches!(placeholder, Err(AocError::MissingInput { .. })));
+    }
+
+    #[test]
+    fn test_from_vars() {
+        let vars = |session: &'static str, url: &'static str| move |name: &str| match name {
+            "AOC_SESSION" => Some(session.to_string()),
+            "AOC_URL" => Some(url.to_string()),
+            _ => None,
+        };
+
+        assert!(Http::from_vars(vars("secret", "")).unwrap_err().to_string().contains("set AOC_URL"));
+        assert!(Http::from_vars(vars("secret", Http::SITE)).unwrap_err().to_string().contains("plain HTTP"));
+        assert_eq!(Http::from_vars(vars("secret", "http://localhost:8080/")).unwrap(), Some(Http::new("http://localhost:8080", "secret")));
+        assert_eq!(Http::from_vars(vars(" ", "http://localhost:8080/")).unwrap(), None);
+        assert_eq!(Http::from_vars(|_| None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_fetch() {
+        let server = StandIn::start(|request| {
+            let logged_in = request.headers.iter().any(|(key, value)| key == "Cookie" && value == "session=secret");
+            match (logged_in, request.url.as_str()) {
+                (false, _) => Response::new(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."),
+                (true, "/2024/day/3/input") => Response::new(200, "3 4 5\n"),
+                (true, _) => Response::new(404, "Not Found"),
+            }
+        }).unwrap();
+
+        let cache = std::env::temp_dir().join(format!("aoc-inputs-fetch-{}", std::process::id()));
+        let store = InputStore::new(&cache).with_backend(Http::new(server.url("/"), "secret"));
+        let result = (store.get(2024, 3), store.get(2024, 4));
+        let cached = std::fs::read_to_string(store.path(2024, 3));
+
+        let anonymous = InputStore::new(&cache).with_backend(Http::new(server.url(""), ""));
+        let rejected = anonymous.get(2024, 5);
+        std::fs::remove_dir_all(&cache).unwrap();
+
+        assert_eq!(result.0.unwrap(), "3 4 5\n");
+        assert!(result.1.unwrap_err().to_string().contains("unlocked"));
+        assert_eq!(cached.unwrap(), "3 4 5\n");
+        assert!(rejected.unwrap_err().to_string().contains("session cookie"));
+    }
+}
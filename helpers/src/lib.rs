@@ -0,0 +1,29 @@
+#![feature(associated_type_defaults)]
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+pub mod answers;
+pub mod cancel;
+pub mod error;
+pub mod filter;
+pub mod guesses;
+pub mod harness;
+pub mod http;
+pub mod inputs;
+pub mod matrix;
+pub mod nums;
+pub mod panics;
+pub mod parallel;
+pub mod submit;
+pub mod vecs;
+pub mod vector;
+
+#[macro_use] mod tiles;
+#[macro_use] pub mod questions;
+pub mod results;
+
+pub use matrix::Matrix;
+pub use nums::Complex;
+pub use parallel::map_reduce;
+pub use vecs::RectVec;
+pub use vector::Vector;
@@ -1,3 +1,5 @@
+use crate::error::AocError;
+
 use super::{vector::Vector, nums::abs};
 
 #[derive(PartialEq, Eq, Hash, Clone)]
@@ -149,11 +151,11 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N>
     /// 
     /// # Examples
     /// ```
-    /// use aoc2023::helpers::matrix::Matrix;
+    /// use aoc_helpers::matrix::Matrix;
     /// 
     /// let matrix: Matrix<_, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
-    /// let transpose = matrix.transpose();
-    /// assert_eq!(transpose.into(), [[1, 4], [2, 5], [3, 6]]);
+    /// let transpose: [[i32; 2]; 3] = matrix.transpose().into();
+    /// assert_eq!(transpose, [[1, 4], [2, 5], [3, 6]]);
     /// ```
     pub fn transpose(&self) -> Matrix<T, N, M> {
         let mut result = Matrix {
@@ -199,7 +201,7 @@ impl<T, const M: usize> Matrix<T, M, { M + 1 }>
         OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
         SOFTWARE.
         */
-    pub fn gauss_jordan_elimination(&mut self) -> Result<Vector<T, M>, Box<dyn std::error::Error>>
+    pub fn gauss_jordan_elimination(&mut self) -> Result<Vector<T, M>, AocError>
     {
         let mut mpivot = 0;
         let mut npivot = 0;
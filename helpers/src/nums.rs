@@ -2,7 +2,7 @@
 /// 
 /// # Examples
 /// ```
-/// use aoc2023::helpers::nums::gcd;
+/// use aoc_helpers::nums::gcd;
 /// 
 /// assert_eq!(gcd(2, 3), 1);
 /// assert_eq!(gcd(3, 4), 1);
@@ -32,7 +32,7 @@ where
 /// 
 /// # Examples
 /// ```
-/// use aoc2023::helpers::nums::lcm;
+/// use aoc_helpers::nums::lcm;
 /// 
 /// assert_eq!(lcm(2, 3), 6);
 /// assert_eq!(lcm(3, 4), 12);
@@ -60,7 +60,7 @@ where
 /// 
 /// # Examples
 /// ```
-/// use aoc2023::helpers::nums::Complex;
+/// use aoc_helpers::nums::Complex;
 /// 
 /// let c1 = Complex::new(1, 2);
 /// let c2 = Complex::new(3, 4);
@@ -0,0 +1,81 @@
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use crate::error::AocError;
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Runs `f`, turning any panic into an [`AocError::Panicked`] which records the panic's message and where
+/// it happened, so that a single broken solution can't take down the rest of the run.
+///
+/// Panics which are caught this way aren't printed by the default panic hook, since they are reported
+/// along with the puzzle's result instead.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::error::AocError;
+/// use aoc_helpers::panics;
+///
+/// let result: Result<(), AocError> = panics::catch(|| panic!("out of bounds"));
+/// assert!(matches!(result, Err(AocError::Panicked { message, .. }) if message == "out of bounds"));
+/// ```
+pub fn catch<R, F: FnOnce() -> Result<R, AocError>>(f: F) -> Result<R, AocError> {
+    install_hook();
+
+    let previous = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(previous));
+
+    result.unwrap_or_else(|payload| {
+        let message = payload.downcast_ref::<&str>().map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic payload".to_string());
+
+        Err(AocError::Panicked { message, location: LOCATION.with(|location| location.take()) })
+    })
+}
+
+/// Wraps the existing panic hook so that panics inside [`catch`] record their location instead of printing.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let location = info.location().map(|location| format!("{}:{}:{}", location.file(), location.line(), location.column()));
+                LOCATION.with(|current| *current.borrow_mut() = location);
+            } else {
+                default(info);
+            }
+        }));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| Ok(1)).unwrap(), 1);
+        assert!(matches!(catch::<(), _>(|| Err(AocError::solve("nope"))), Err(AocError::Solve { .. })));
+
+        let values: Vec<u32> = Vec::new();
+        match catch(|| Ok(values[0])) {
+            Err(AocError::Panicked { message, location }) => {
+                assert!(message.contains("index out of bounds"), "{message}");
+                assert!(location.is_some_and(|location| location.starts_with("helpers/src/panics.rs:")));
+            },
+            other => panic!("expected a panic, got {other:?}"),
+        }
+
+        let name = "Day5";
+        let err = catch::<(), _>(|| panic!("{name} broke")).unwrap_err().to_string();
+        assert!(err.starts_with("panicked at helpers/src/panics.rs:") && err.ends_with(": Day5 broke"), "{err}");
+    }
+}
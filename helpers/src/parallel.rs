@@ -1,5 +1,16 @@
 use std::{sync::mpsc, thread, collections::VecDeque};
 
+/// Maps each item of the input on a pool of worker threads, then folds the results together on the
+/// calling thread. Results are reduced in the order they complete, so the reducer should not depend on
+/// the order of the input.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::map_reduce;
+///
+/// let sum = map_reduce(1..=100u64, |x| x * x, 0, |acc, x| acc + x);
+/// assert_eq!(sum, 338350);
+/// ```
 pub fn map_reduce<I, M, N, R, O>(input: I, mapper: M, initial: O, reducer: R) -> O
     where M: Fn(I::Item) -> N + Send + Clone + 'static,
           R: Fn(O, N) -> O + 'static,
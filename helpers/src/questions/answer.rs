@@ -0,0 +1,171 @@
+/// The answer to one part of a puzzle.
+///
+/// Integer answers are always stored in the smallest variant which can hold them, so that two answers
+/// with the same value compare equal regardless of the type they were produced from. Text which is written
+/// exactly like a number (such as an answer read back from a file) is stored as that number too.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+    /// Multi-line text, usually a picture drawn by the puzzle which needs to be read by eye.
+    Grid(String),
+}
+
+impl Answer {
+    /// Returns the value of this answer if it is a number.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Self::Integer(value) => Some(*value as i128),
+            Self::BigInteger(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn is_grid(&self) -> bool {
+        matches!(self, Self::Grid(_))
+    }
+}
+
+impl Default for Answer {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::BigInteger(value) => write!(f, "{value}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Grid(grid) => write!(f, "{grid}"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Text(a), Self::Text(b)) => a == b,
+            (Self::Grid(a), Self::Grid(b)) => grid_lines(a).eq(grid_lines(b)),
+            (a, b) => a.as_integer().is_some() && a.as_integer() == b.as_integer(),
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Self::Integer(_) | Self::BigInteger(_) => other.trim().parse::<i128>().ok() == self.as_integer(),
+            Self::Text(text) => text == other.trim(),
+            Self::Grid(grid) => grid_lines(grid).eq(grid_lines(other)),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        text.to_string().into()
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        if text.trim().contains('\n') {
+            return Self::Grid(text);
+        }
+
+        // Only numbers written the way they would be displayed are converted, so `0123` stays as text.
+        match text.trim().parse::<i128>() {
+            Ok(value) if value.to_string() == text.trim() => value.into(),
+            _ => Self::Text(text),
+        }
+    }
+}
+
+/// Compares grids line by line, ignoring the indentation and blank lines which surround them in examples.
+fn grid_lines(grid: &str) -> impl Iterator<Item = &str> {
+    grid.trim().lines().map(|line| line.trim())
+}
+
+macro_rules! integer_answer {
+    ($($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Self::Integer(value),
+                        Err(_) => match i128::try_from(value) {
+                            Ok(value) => Self::BigInteger(value),
+                            Err(_) => Self::Text(value.to_string()),
+                        },
+                    }
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    match i128::try_from(*other) {
+                        Ok(other) => self.as_integer() == Some(other),
+                        // Values which don't fit in an i128 are stored as text.
+                        Err(_) => *self == *other.to_string(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+integer_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(Answer::from(123u8), Answer::Integer(123));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(u64::MAX), u64::MAX);
+        assert_eq!(Answer::from(42usize), 42);
+        assert_eq!(Answer::from(42usize).as_integer(), Some(42));
+        assert_eq!(Answer::from(-7i32).to_string(), "-7");
+        assert_eq!(Answer::from(u128::MAX), u128::MAX);
+        assert_eq!(Answer::from(u128::MAX), Answer::from(u128::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_literals() {
+        assert_eq!(Answer::from(123), "123");
+        assert_eq!(Answer::from(123), "0123");
+        assert_ne!(Answer::from(123), "124");
+        assert_eq!(Answer::from("abc"), "abc");
+        assert_ne!(Answer::from("0123"), "123");
+        assert_eq!(Answer::from("123"), Answer::from(123));
+        assert_eq!(Answer::from("-7"), Answer::Integer(-7));
+        assert_ne!(Answer::from("0123"), Answer::from(123));
+    }
+
+    #[test]
+    fn test_grid() {
+        let answer = Answer::from("#..#\n####\n");
+        assert!(answer.is_grid());
+        assert_eq!(answer, r#"
+            #..#
+            ####
+        "#);
+    }
+}
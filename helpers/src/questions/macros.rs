@@ -0,0 +1,203 @@
+/// Generates the tests used to run a daily question and validate it against the test input, along with
+/// the `SOLUTION` which registers it with its year and implementations of
+/// [`ExampleInput`](crate::questions::ExampleInput) and [`RealInput`](crate::questions::RealInput).
+///
+/// The crate for each year must define a `year!()` macro which expands to its year before declaring any
+/// questions, since this is used to find their inputs under `inputs/<year>/`. Inputs are resolved when the
+/// tests run (see [`InputStore`](crate::inputs::InputStore)), and a day without one only runs its examples.
+/// 
+/// # Example
+/// ```ignore
+/// question!(Day1, validate = [
+///     {
+///        input: r#"input"#
+///        part1: 1,
+///        part2: "two"
+///     },
+/// ], slow="part 2 tries every position");
+/// ```
+///
+/// Each example can also have a `name` (used when reporting it), the question's `params` to solve it with
+/// (see [`Question`](crate::questions::Question)), and a `file: k` to read its input from
+/// `inputs/examples/DayN-k.txt` instead of giving it inline. The fields can be written in any order.
+///
+/// ```ignore
+/// question!(Day18, validate = [
+///     {
+///        name: "small grid",
+///        file: 1,
+///        params: Grid { size: 7, bytes: 12 },
+///        part1: 22,
+///        part2: "6,1"
+///     },
+/// ]);
+/// ```
+///
+/// The generated tests respect the same `AOC_DAYS`, `AOC_PART` and `AOC_ONLY` environment variables as
+/// the `solutions` harness (see [`Filter::from_env`](crate::filter::Filter::from_env)), so for example
+/// `AOC_DAYS=5 AOC_PART=2 AOC_ONLY=real cargo test` only runs the real input for day 5's second part.
+///
+/// Days which are too slow for the continuous test loop can be marked `slow="reason"`, so that their real
+/// input only runs when slow days are included (with `AOC_SLOW=1` or `--include-slow`), while unfinished
+/// days can be marked `skip="reason"` so that they don't run at all.
+///
+/// Each part is given [`results::timeout`](crate::results::timeout) to run in, after which it is reported
+/// as having timed out. Slow days can ask for more (or less) time with a `timeout=Duration::from_secs(30)`
+/// option after `skip` or `slow`.
+#[macro_export]
+macro_rules! question {
+    (@optional) => { None };
+    (@optional $value:expr) => { Some($value) };
+    (@tier) => { $crate::questions::Tier::Normal };
+    (@tier skip $reason:expr) => { $crate::questions::Tier::Skip($reason) };
+    (@tier , slow $reason:expr) => { $crate::questions::Tier::Slow($reason) };
+    (@tier skip $skip:expr, slow $slow:expr) => { compile_error!("a question can be skipped or slow, but not both") };
+
+    (@field $day:ident name $value:expr) => { Some($value) };
+    (@field $day:ident input $value:expr) => { $value };
+    (@field $day:ident file $value:expr) => { Some($value) };
+    (@field $day:ident params $value:expr) => {{
+        const PARAMS: &<$day as $crate::questions::Question>::Params = &$value;
+        Some(PARAMS as &(dyn ::std::any::Any + Sync))
+    }};
+    (@field $day:ident part1 $value:expr) => { Some(concat!($value)) };
+    (@field $day:ident part2 $value:expr) => { Some(concat!($value)) };
+
+    ($day:ident, validate = [
+        $(
+            {
+                $($field:ident: $value:expr),+ $(,)?
+            }
+        ),+ $(,)?
+    ]
+    $(, skip=$skip:expr)?
+    $(, slow=$slow:expr)?
+    $(, timeout=$timeout:expr)?) => {
+        pub struct $day;
+
+        pub const SOLUTION: $crate::questions::Solution = $crate::questions::Solution {
+            name: stringify!($day),
+            year: year!(),
+            day: $crate::questions::day_number(stringify!($day)),
+            parse: $crate::questions::erased::parse::<$day>,
+            part1: $crate::questions::erased::part1::<$day>,
+            part2: $crate::questions::erased::part2::<$day>,
+            examples: &[
+                $(
+                    $crate::questions::Example {
+                        $($field: $crate::question!(@field $day $field $value),)+
+                        ..$crate::questions::Example::DEFAULT
+                    },
+                )+
+            ],
+            tier: $crate::question!(@tier $(skip $skip)? $(, slow $slow)?),
+            timeout: $crate::question!(@optional $($timeout)?),
+        };
+
+        impl $crate::questions::ExampleInput for $day {
+            fn part1_test_input(store: &$crate::inputs::InputStore) -> Result<::std::borrow::Cow<'static, str>, $crate::error::AocError> {
+                SOLUTION.test_input(1, store)
+            }
+
+            fn part2_test_input(store: &$crate::inputs::InputStore) -> Result<::std::borrow::Cow<'static, str>, $crate::error::AocError> {
+                SOLUTION.test_input(2, store)
+            }
+        }
+
+        impl $crate::questions::RealInput for $day {
+            fn real_input(store: &$crate::inputs::InputStore) -> Result<String, $crate::error::AocError> {
+                store.get(SOLUTION.year, SOLUTION.day)
+            }
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn part1() -> Result<(), $crate::error::AocError> {
+            use $crate::questions::Question;
+
+            let filter = $crate::filter::Filter::from_env()?;
+            if !filter.includes(SOLUTION.day, 1) {
+                return Ok(());
+            }
+
+            if let $crate::questions::Tier::Skip(reason) = SOLUTION.tier {
+                println!("{}", $crate::results::PuzzleResult::skipped(concat!(stringify!($day), ".1")).because(reason));
+                return Ok(());
+            }
+
+            if filter.examples() {
+                $crate::harness::check_examples(&SOLUTION, 1, concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs").as_ref())?;
+            }
+
+            if !filter.real() {
+                return Ok(());
+            }
+
+            if let $crate::questions::Tier::Slow(reason) = SOLUTION.tier {
+                if !filter.include_slow {
+                    println!("{}", $crate::results::PuzzleResult::slow(concat!(stringify!($day), ".1")).because(reason));
+                    return Ok(());
+                }
+            }
+
+            let store = $crate::inputs::InputStore::from_env(concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs"))?;
+            let input = match <$day as $crate::questions::RealInput>::real_input(&store) {
+                Ok(input) => input,
+                Err(err @ $crate::error::AocError::MissingInput { .. }) => {
+                    println!("{}", $crate::results::PuzzleResult::failed(concat!(stringify!($day), ".1"), &err));
+                    return Ok(());
+                },
+                Err(err) => return Err(err.in_puzzle(SOLUTION.day, None)),
+            };
+
+            $crate::results::run_with_timeout(concat!(stringify!($day), ".1"), SOLUTION.timeout.unwrap_or_else($crate::results::timeout), move || {
+                <$day as Question>::parse(input.trim())
+            }, |parsed| <$day as Question>::part1_with(parsed, &Default::default())).map_err(|err| err.in_puzzle(SOLUTION.day, Some(1)))
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn part2() -> Result<(), $crate::error::AocError> {
+            use $crate::questions::Question;
+
+            let filter = $crate::filter::Filter::from_env()?;
+            if !filter.includes(SOLUTION.day, 2) {
+                return Ok(());
+            }
+
+            if let $crate::questions::Tier::Skip(reason) = SOLUTION.tier {
+                println!("{}", $crate::results::PuzzleResult::skipped(concat!(stringify!($day), ".2")).because(reason));
+                return Ok(());
+            }
+
+            if filter.examples() {
+                $crate::harness::check_examples(&SOLUTION, 2, concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs").as_ref())?;
+            }
+
+            if !filter.real() {
+                return Ok(());
+            }
+
+            if let $crate::questions::Tier::Slow(reason) = SOLUTION.tier {
+                if !filter.include_slow {
+                    println!("{}", $crate::results::PuzzleResult::slow(concat!(stringify!($day), ".2")).because(reason));
+                    return Ok(());
+                }
+            }
+
+            let store = $crate::inputs::InputStore::from_env(concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs"))?;
+            let input = match <$day as $crate::questions::RealInput>::real_input(&store) {
+                Ok(input) => input,
+                Err(err @ $crate::error::AocError::MissingInput { .. }) => {
+                    println!("{}", $crate::results::PuzzleResult::failed(concat!(stringify!($day), ".2"), &err));
+                    return Ok(());
+                },
+                Err(err) => return Err(err.in_puzzle(SOLUTION.day, None)),
+            };
+
+            $crate::results::run_with_timeout(concat!(stringify!($day), ".2"), SOLUTION.timeout.unwrap_or_else($crate::results::timeout), move || {
+                <$day as Question>::parse(input.trim())
+            }, |parsed| <$day as Question>::part2_with(parsed, &Default::default())).map_err(|err| err.in_puzzle(SOLUTION.day, Some(2)))
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,371 @@
+use std::any::Any;
+use std::borrow::Cow;
+
+#[macro_use] mod macros;
+mod answer;
+
+pub use answer::Answer;
+
+use crate::error::AocError;
+use crate::inputs::InputStore;
+
+/// Parses a day's input, into a form which can be shared between the threads its parts run on.
+pub type Parser = fn(&str) -> Result<Box<dyn Any + Send + Sync>, AocError>;
+/// Solves a part from the parsed input, using the example's parameters if it has any (and the question's
+/// default parameters otherwise).
+pub type Solver = fn(&dyn Any, Option<&dyn Any>) -> Result<Answer, AocError>;
+
+/// A day's solution, which parses its input once and then solves each part from the parsed form.
+///
+/// Puzzles which use different settings for their examples than for the real input (such as a smaller
+/// grid) can describe them with `Params`, whose default is used for the real input, and implement
+/// `part1_with` and `part2_with` to receive them.
+#[allow(unused_variables)]
+pub trait Question {
+    /// The parsed form of the input, which is shared between both parts (so it must be thread safe).
+    type Parsed: Send + Sync + 'static;
+    type Params: Default + Sync + 'static = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError>;
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, AocError> {
+        todo!();
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer, AocError> {
+        todo!();
+    }
+
+    fn part1_with(input: &Self::Parsed, params: &Self::Params) -> Result<Answer, AocError> {
+        Self::part1(input)
+    }
+
+    fn part2_with(input: &Self::Parsed, params: &Self::Params) -> Result<Answer, AocError> {
+        Self::part2(input)
+    }
+}
+
+/// A day's solution which works directly on the raw puzzle input.
+///
+/// Every [`RawQuestion`] is also a [`Question`] whose parsed form is just the input itself, so days
+/// can be moved over to a dedicated parser one at a time.
+#[allow(unused_variables)]
+pub trait RawQuestion {
+    fn part1(input: &str) -> Result<Answer, AocError> {
+        todo!();
+    }
+
+    fn part2(input: &str) -> Result<Answer, AocError> {
+        todo!();
+    }
+}
+
+impl<Q: RawQuestion> Question for Q {
+    type Parsed = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, AocError> {
+        <Q as RawQuestion>::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer, AocError> {
+        <Q as RawQuestion>::part2(input)
+    }
+}
+
+/// Gives access to the example inputs a question is checked against, so that other tools (such as a
+/// visualiser) can run the question on them. `question!` implements this for every day.
+///
+/// Examples given with `file` are read from the store, which is why this can fail.
+pub trait ExampleInput {
+    /// The input of the first example which has an answer for part 1.
+    fn part1_test_input(store: &InputStore) -> Result<Cow<'static, str>, AocError>;
+
+    /// The input of the first example which has an answer for part 2.
+    fn part2_test_input(store: &InputStore) -> Result<Cow<'static, str>, AocError> {
+        Self::part1_test_input(store)
+    }
+}
+
+/// Gives access to a question's real puzzle input, which is resolved at runtime through an [`InputStore`]
+/// (and so is downloaded if the store can, or reported as missing otherwise). `question!` implements this
+/// for every day.
+pub trait RealInput {
+    fn real_input(store: &InputStore) -> Result<String, AocError>;
+}
+
+/// The solutions for a single event year, which each year's crate exposes so that they can be registered
+/// with the runner.
+pub struct Year {
+    pub year: u16,
+    /// All of the days which have been implemented so far, in order.
+    pub solutions: &'static [Solution],
+}
+
+impl Year {
+    /// Looks up the solution for the given day, if that day has been implemented.
+    pub fn get(&self, day: u8) -> Option<&'static Solution> {
+        self.solutions.iter().find(|solution| solution.day == day)
+    }
+}
+
+/// A registered day's solution, generated by the `question!` macro.
+pub struct Solution {
+    pub name: &'static str,
+    pub year: u16,
+    pub day: u8,
+    pub parse: Parser,
+    pub part1: Solver,
+    pub part2: Solver,
+    pub examples: &'static [Example],
+    /// Whether the day runs with everything else, only when slow days are asked for, or not at all.
+    pub tier: Tier,
+    /// The time budget for each part, if it needs something other than [`results::timeout`](crate::results::timeout).
+    pub timeout: Option<std::time::Duration>,
+}
+
+/// When a day's solution is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tier {
+    /// Runs along with everything else.
+    Normal,
+    /// Too slow for the continuous test loop, so its real input only runs when slow days are included (its
+    /// examples still run every time). Holds the reason it is slow.
+    Slow(&'static str),
+    /// Never runs (such as while the day is unfinished). Holds the reason it is skipped.
+    Skip(&'static str),
+}
+
+impl Solution {
+    pub fn part(&self, part: u8) -> Solver {
+        if part == 1 { self.part1 } else { self.part2 }
+    }
+
+    /// The name used to report one of this solution's examples, such as `Day18.1 (small grid)`.
+    pub fn example_name(&self, i: usize, part: u8) -> String {
+        format!("{}.{part} ({})", self.name, self.examples[i].label(i))
+    }
+
+    /// The input of the first example which has an answer for a part (see [`ExampleInput`]).
+    pub fn test_input(&self, part: u8, store: &InputStore) -> Result<Cow<'static, str>, AocError> {
+        match self.examples.iter().find(|example| example.answer(part).is_some()) {
+            Some(example) => self.example_input(example, store),
+            None => Err(AocError::solve("there is no example for this part").in_puzzle(self.day, Some(part))),
+        }
+    }
+
+    /// The input for one of this solution's examples, which is either written inline or read from
+    /// `inputs/examples/DayN-k.txt`.
+    pub fn example_input(&self, example: &Example, store: &InputStore) -> Result<Cow<'static, str>, AocError> {
+        match example.file {
+            Some(k) => store.example(self.name, k).map(Cow::Owned),
+            None => Ok(Cow::Borrowed(example.input)),
+        }
+    }
+}
+
+/// Type-erased adapters which allow a [`Question`] to be stored in a [`Solution`].
+pub mod erased {
+    use std::any::Any;
+
+    use crate::error::AocError;
+
+    use super::{Answer, Question};
+
+    pub fn parse<Q: Question>(input: &str) -> Result<Box<dyn Any + Send + Sync>, AocError> {
+        Ok(Box::new(Q::parse(input)?))
+    }
+
+    pub fn part1<Q: Question>(parsed: &dyn Any, params: Option<&dyn Any>) -> Result<Answer, AocError> {
+        match params {
+            Some(params) => Q::part1_with(downcast::<Q>(parsed)?, downcast_params::<Q>(params)?),
+            None => Q::part1_with(downcast::<Q>(parsed)?, &Q::Params::default()),
+        }
+    }
+
+    pub fn part2<Q: Question>(parsed: &dyn Any, params: Option<&dyn Any>) -> Result<Answer, AocError> {
+        match params {
+            Some(params) => Q::part2_with(downcast::<Q>(parsed)?, downcast_params::<Q>(params)?),
+            None => Q::part2_with(downcast::<Q>(parsed)?, &Q::Params::default()),
+        }
+    }
+
+    fn downcast<Q: Question>(parsed: &dyn Any) -> Result<&Q::Parsed, AocError> {
+        parsed.downcast_ref().ok_or_else(|| "The parsed input does not belong to this question.".into())
+    }
+
+    fn downcast_params<Q: Question>(params: &dyn Any) -> Result<&Q::Params, AocError> {
+        params.downcast_ref().ok_or_else(|| "The example's parameters do not belong to this question.".into())
+    }
+}
+
+/// An example input from the puzzle description, along with the answers it is expected to produce
+/// (written the same way as they would be entered on the website).
+pub struct Example {
+    /// What the example is called in reports, if it is more helpful than its number.
+    pub name: Option<&'static str>,
+    pub input: &'static str,
+    /// Reads the input from `inputs/examples/DayN-k.txt` (for this `k`) rather than using `input`.
+    pub file: Option<u8>,
+    /// The question's `Params` to solve this example with, instead of the defaults.
+    pub params: Option<&'static (dyn Any + Sync)>,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    /// An example with no input or answers, which `question!` fills in with the fields it was given.
+    pub const DEFAULT: Example = Example { name: None, input: "", file: None, params: None, part1: None, part2: None };
+
+    pub fn answer(&self, part: u8) -> Option<&'static str> {
+        if part == 1 { self.part1 } else { self.part2 }
+    }
+
+    pub fn params(&self) -> Option<&'static dyn Any> {
+        self.params.map(|params| params as &dyn Any)
+    }
+
+    /// The example's name, or its (1-based) number if it doesn't have one.
+    pub fn label(&self, i: usize) -> Cow<'static, str> {
+        match self.name {
+            Some(name) => Cow::Borrowed(name),
+            None => Cow::Owned(format!("example {}", i + 1)),
+        }
+    }
+}
+
+/// Determines the day number from the name of a question type (i.e. `Day12` is day 12).
+pub const fn day_number(name: &str) -> u8 {
+    let bytes = name.as_bytes();
+    let mut day = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_digit() {
+            day = day * 10 + (bytes[i] - b'0');
+        }
+
+        i += 1;
+    }
+
+    day
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! year {
+        () => { 2015 };
+    }
+
+    /// How many of the numbers to add up, which is all of them for the real input.
+    pub struct Count(usize);
+
+    impl Default for Count {
+        fn default() -> Self {
+            Count(usize::MAX)
+        }
+    }
+
+    mod sums {
+        use crate::error::AocError;
+        use crate::questions::{Answer, Question};
+
+        use super::Count;
+
+        question!(Day25, validate = [
+            {
+                name: "first three",
+                input: "1 2 3 4 5",
+                params: Count(3),
+                part1: 6,
+            },
+            {
+                input: "1 2 3 4 5",
+                part1: 15,
+                part2: "5 4 3 2 1"
+            }
+        ]);
+
+        impl Question for Day25 {
+            type Parsed = Vec<u64>;
+            type Params = Count;
+
+            fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+                Ok(input.split_whitespace().map(str::parse).collect::<Result<_, _>>()?)
+            }
+
+            fn part1_with(input: &Vec<u64>, params: &Count) -> Result<Answer, AocError> {
+                Ok(input.iter().take(params.0).sum::<u64>().into())
+            }
+
+            fn part2(input: &Vec<u64>) -> Result<Answer, AocError> {
+                Ok(input.iter().rev().map(u64::to_string).collect::<Vec<_>>().join(" ").into())
+            }
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        let solution = &sums::SOLUTION;
+        assert_eq!(solution.example_name(0, 1), "Day25.1 (first three)");
+        assert_eq!(solution.example_name(1, 2), "Day25.2 (example 2)");
+
+        let store = InputStore::new("inputs");
+        let parsed = (solution.parse)(&solution.example_input(&solution.examples[0], &store).unwrap()).unwrap();
+        assert_eq!((solution.part1)(parsed.as_ref(), solution.examples[0].params()).unwrap(), "6");
+        assert_eq!((solution.part1)(parsed.as_ref(), None).unwrap(), "15");
+
+        let wrong = Solution {
+            examples: &[Example { name: Some("off by one"), input: "1 2 3", part1: Some("7"), ..Example::DEFAULT }],
+            ..sums::SOLUTION
+        };
+
+        let err = crate::harness::check_examples(&wrong, 1, "inputs".as_ref()).unwrap_err();
+        assert_eq!(err.to_string(), "Day25.1: 'off by one' produced 6 instead of 7");
+    }
+
+    #[test]
+    fn test_inputs() {
+        let cache = std::env::temp_dir().join(format!("aoc-question-inputs-{}", std::process::id()));
+        let store = InputStore::new(&cache).with_backend(|_, _| Ok::<_, AocError>("4 5 6\n".to_string()));
+        assert_eq!(<sums::Day25 as ExampleInput>::part1_test_input(&store).unwrap(), "1 2 3 4 5");
+        assert_eq!(<sums::Day25 as ExampleInput>::part2_test_input(&store).unwrap(), "1 2 3 4 5");
+
+        let real = <sums::Day25 as RealInput>::real_input(&store);
+        std::fs::remove_dir_all(&cache).unwrap();
+        assert_eq!(real.unwrap(), "4 5 6\n");
+
+        let none = Solution { examples: &[], ..sums::SOLUTION };
+        assert_eq!(none.test_input(2, &store).unwrap_err().to_string(), "Day25.2: there is no example for this part");
+    }
+
+    #[test]
+    fn test_tiers() {
+        let cache = std::env::temp_dir().join(format!("aoc-tiers-{}", std::process::id()));
+        let run = |tier, filter: &crate::filter::Filter| {
+            let solutions = Box::leak(Box::new([Solution { tier, ..sums::SOLUTION }]));
+            crate::harness::run_solutions(2015, solutions, &cache, filter).unwrap().iter()
+                .map(|result| format!("{} {}", result.name(), result.status()))
+                .collect::<Vec<_>>()
+        };
+
+        let slow = run(Tier::Slow("too slow"), &Default::default());
+        assert_eq!(slow, ["Day25.1 (first three) ok", "Day25.1 (example 2) ok", "Day25.1 slow", "Day25.2 (example 2) ok", "Day25.2 slow"]);
+
+        let included = run(Tier::Slow("too slow"), &crate::filter::Filter { include_slow: true, ..Default::default() });
+        assert_eq!(included[2], "Day25.1 no input");
+
+        assert_eq!(run(Tier::Skip("unfinished"), &Default::default()), ["Day25.1 skipped", "Day25.2 skipped"]);
+    }
+
+    #[test]
+    fn test_day_number() {
+        assert_eq!(day_number("Day1"), 1);
+        assert_eq!(day_number("Day25"), 25);
+        assert_eq!(day_number("DayN"), 0);
+    }
+}
@@ -0,0 +1,550 @@
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+use crate::cancel::CancellationToken;
+use crate::error::AocError;
+use crate::panics;
+use crate::questions::Answer;
+
+/// How long a puzzle may run for when it doesn't set its own budget.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The default time budget for each puzzle, which may be overridden with the `AOC_TIMEOUT` environment
+/// variable (in seconds).
+pub fn timeout() -> Duration {
+    std::env::var("AOC_TIMEOUT").ok()
+        .and_then(|secs| secs.trim().parse::<f64>().ok())
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .unwrap_or(DEFAULT_TIMEOUT)
+}
+
+/// Runs a puzzle from one of the generated tests, printing its result (which `cargo test` shows when run
+/// with `--nocapture`) and returning any error it produced.
+pub fn run<D: ToString, T, V: Into<Answer>, P, F>(day: D, parse: P, run: F) -> Result<(), AocError>
+    where P: FnOnce() -> Result<T, AocError> + Send + 'static,
+          F: FnOnce(&T) -> Result<V, AocError> + Send + 'static
+{
+    run_with_timeout(day, timeout(), parse, run)
+}
+
+/// Like [`run`], but with a specific time budget, after which the puzzle is reported as having timed out.
+pub fn run_with_timeout<D: ToString, T, V: Into<Answer>, P, F>(day: D, timeout: Duration, parse: P, run: F) -> Result<(), AocError>
+    where P: FnOnce() -> Result<T, AocError> + Send + 'static,
+          F: FnOnce(&T) -> Result<V, AocError> + Send + 'static
+{
+    let (result, err) = PuzzleResult::measure_with_timeout(day, timeout, parse, run);
+    println!("{result}");
+
+    err.map(Err).unwrap_or(Ok(()))
+}
+
+/// Parses a puzzle's input on its own thread (catching any panic), giving up on it once `timeout` has
+/// elapsed, and returns the parsed input along with how long it took. The parts can then share it through
+/// [`PuzzleResult::measure_parsed_with_timeout`].
+pub fn parse_with_timeout<T, P>(name: &str, timeout: Duration, parse: P) -> Result<(T, Duration), AocError>
+    where T: Send + 'static,
+          P: FnOnce() -> Result<T, AocError> + Send + 'static
+{
+    on_thread(name, timeout, move || {
+        let start = std::time::Instant::now();
+        panics::catch(parse).map(|parsed| (parsed, start.elapsed()))
+    })?
+}
+
+/// Runs `f` on a new thread (named after the puzzle) with its own [`CancellationToken`], which is
+/// cancelled if it hasn't finished once `timeout` has elapsed.
+fn on_thread<R, F>(name: &str, timeout: Duration, f: F) -> Result<R, AocError>
+    where R: Send + 'static,
+          F: FnOnce() -> R + Send + 'static
+{
+    let token = CancellationToken::new();
+    let (tx, rx) = mpsc::channel();
+
+    let worker = {
+        let token = token.clone();
+        std::thread::Builder::new().name(name.to_string()).spawn(move || {
+            let _ = tx.send(token.run(f));
+        })
+    };
+
+    if let Err(err) = worker {
+        return Err(AocError::solve("could not start a thread to run the puzzle on").caused_by(err));
+    }
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => Ok(result),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            token.cancel();
+            Err(AocError::Timeout { limit: timeout })
+        },
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(AocError::solve("the puzzle stopped without producing an answer")),
+    }
+}
+
+/// The results of a run, in the order they were added, which are displayed as a table followed by a
+/// summary of how many parts passed, failed and were skipped.
+#[derive(Debug, Clone, Default)]
+pub struct ResultContainer(Vec<PuzzleResult>);
+
+impl ResultContainer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, result: PuzzleResult) {
+        self.0.push(result);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PuzzleResult> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The number of results which need fixing (see [`Status::is_failure`]).
+    pub fn failures(&self) -> usize {
+        self.0.iter().filter(|result| result.status().is_failure()).count()
+    }
+}
+
+impl FromIterator<PuzzleResult> for ResultContainer {
+    fn from_iter<I: IntoIterator<Item = PuzzleResult>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl std::fmt::Display for ResultContainer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "Nothing was run.");
+        }
+
+        let width = self.0.iter().map(|result| result.name.len()).chain(["Puzzle".len()]).max().unwrap_or_default();
+        let answers = self.0.iter().map(|result| match (&result.answer, &result.error) {
+            (_, Some(err)) => err.clone(),
+            (None, None) if result.reason.is_some() => result.reason.clone().unwrap_or_default(),
+            (Some(answer), _) if answer.is_grid() => "(grid)".to_string(),
+            (Some(answer), _) => match &result.expected {
+                Some(expected) => format!("{answer} (expected {expected})"),
+                None => answer.to_string(),
+            },
+            (None, None) => String::new(),
+        }).collect::<Vec<_>>();
+        let answer_width = answers.iter().map(|answer| answer.chars().count()).chain(["Answer".len()]).max().unwrap_or_default();
+
+        writeln!(f, "{:<width$}  {:<12}  {:<answer_width$}  {:>9}  {:>9}", "Puzzle", "Status", "Answer", "Time", "Parsing")?;
+        writeln!(f, "{}", "-".repeat(width + answer_width + 12 + 9 * 2 + 8))?;
+
+        for (result, answer) in self.0.iter().zip(answers) {
+            let row = match result.status {
+                Status::Skipped | Status::MissingInput | Status::Slow => format!("{:<width$}  {:<12}  {answer}", result.name, result.status),
+                _ => format!("{:<width$}  {:<12}  {:<answer_width$}  {:>9}  {:>9}", result.name, result.status, answer, Elapsed(result.duration), Elapsed(result.parse_duration)),
+            };
+            writeln!(f, "{}", row.trim_end())?;
+
+            if let Some(answer) = result.answer.as_ref().filter(|answer| answer.is_grid()) {
+                for line in answer.to_string().trim_end().lines() {
+                    writeln!(f, "    {line}")?;
+                }
+            }
+        }
+
+        let count = |status: Status| self.0.iter().filter(|result| result.status == status).count();
+        writeln!(f, "{}", "-".repeat(width + answer_width + 12 + 9 * 2 + 8))?;
+        write!(f, "{} passed, {} failed, {} skipped", count(Status::Ok), self.failures(), count(Status::Skipped))?;
+
+        if let slow @ 1.. = count(Status::Slow) {
+            write!(f, ", {slow} slow")?;
+        }
+
+        match count(Status::MissingInput) {
+            0 => Ok(()),
+            missing => write!(f, ", {missing} missing input(s)"),
+        }
+    }
+}
+
+/// How running a single part of a puzzle went.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Status {
+    Ok,
+    /// The puzzle produced an answer, but it didn't match the one we expected.
+    WrongAnswer,
+    Error,
+    Panicked,
+    Skipped,
+    Timeout,
+    /// The day's input hasn't been downloaded, so only its examples could be run.
+    MissingInput,
+    /// The day is too slow to run with everything else, so its real input was left out.
+    Slow,
+}
+
+impl Status {
+    /// The status used to report a puzzle which failed with the given error.
+    pub fn of(err: &AocError) -> Self {
+        match err {
+            AocError::Timeout { .. } => Status::Timeout,
+            AocError::Panicked { .. } => Status::Panicked,
+            AocError::MissingInput { .. } => Status::MissingInput,
+            AocError::Puzzle { source, .. } => Status::of(source),
+            _ => Status::Error,
+        }
+    }
+
+    /// Whether this status means that something needs fixing.
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Status::Ok | Status::Skipped | Status::MissingInput | Status::Slow)
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => f.pad("ok"),
+            Status::WrongAnswer => f.pad("wrong answer"),
+            Status::Error => f.pad("error"),
+            Status::Panicked => f.pad("panicked"),
+            Status::Skipped => f.pad("skipped"),
+            Status::Timeout => f.pad("timeout"),
+            Status::MissingInput => f.pad("no input"),
+            Status::Slow => f.pad("slow"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PuzzleResult {
+    name: String,
+    day: Option<u8>,
+    part: Option<u8>,
+    status: Status,
+    answer: Option<Answer>,
+    expected: Option<String>,
+    error: Option<String>,
+    /// Why the puzzle wasn't run, if it was skipped or left out for being slow.
+    reason: Option<String>,
+    duration: Duration,
+    parse_duration: Duration,
+}
+
+impl PuzzleResult {
+    /// Runs a single part of a puzzle, timing how long it takes to produce its answer.
+    pub fn measure<D: ToString, V: Into<Answer>, F>(day: D, run: F) -> (Self, Option<AocError>)
+        where F: FnOnce() -> Result<V, AocError>
+    {
+        let start = std::time::Instant::now();
+        match panics::catch(run) {
+            Ok(answer) => (Self { answer: Some(answer.into()), duration: start.elapsed(), ..Self::new(day, Status::Ok) }, None),
+            Err(err) => (Self { duration: start.elapsed(), ..Self::failed(day, &err) }, Some(err)),
+        }
+    }
+
+    /// Parses the input for a puzzle and then runs a single part against it, timing each step separately.
+    pub fn measure_parsed<D: ToString, T, V: Into<Answer>, P, F>(day: D, parse: P, run: F) -> (Self, Option<AocError>)
+        where P: FnOnce() -> Result<T, AocError>,
+              F: FnOnce(&T) -> Result<V, AocError>
+    {
+        let start = std::time::Instant::now();
+        match panics::catch(parse) {
+            Ok(parsed) => {
+                let parse_duration = start.elapsed();
+                let (result, err) = Self::measure(day, || run(&parsed));
+                (result.with_parse_duration(parse_duration), err)
+            },
+            Err(err) => (Self { parse_duration: start.elapsed(), ..Self::failed(day, &err) }, Some(err)),
+        }
+    }
+
+    /// Parses and runs a puzzle on its own thread, giving up on it once `timeout` has elapsed. When that
+    /// happens, the puzzle's [`CancellationToken`] is cancelled so that it can stop cooperatively, and it is
+    /// reported with a [`Status::Timeout`] so that everything else can carry on.
+    pub fn measure_with_timeout<D: ToString, T, V: Into<Answer>, P, F>(day: D, timeout: Duration, parse: P, run: F) -> (Self, Option<AocError>)
+        where P: FnOnce() -> Result<T, AocError> + Send + 'static,
+              F: FnOnce(&T) -> Result<V, AocError> + Send + 'static
+    {
+        let name = day.to_string();
+        match on_thread(&name, timeout, { let name = name.clone(); move || Self::measure_parsed(name, parse, run) }) {
+            Ok(result) => result,
+            Err(err @ AocError::Timeout { .. }) => (Self { duration: timeout, ..Self::failed(name, &err) }, Some(err)),
+            Err(err) => (Self::failed(name, &err), Some(err)),
+        }
+    }
+
+    /// Runs a single part of a puzzle against an input which has already been parsed (see
+    /// [`parse_with_timeout`]), so that the parts can share it. Like [`measure_with_timeout`](Self::measure_with_timeout),
+    /// the part runs on its own thread and is given up on once `timeout` has elapsed.
+    pub fn measure_parsed_with_timeout<D: ToString, T, V: Into<Answer>, F>(day: D, timeout: Duration, parsed: Arc<T>, run: F) -> (Self, Option<AocError>)
+        where T: ?Sized + Send + Sync + 'static,
+              F: FnOnce(&T) -> Result<V, AocError> + Send + 'static
+    {
+        let name = day.to_string();
+        match on_thread(&name, timeout, { let name = name.clone(); move || Self::measure(name, || run(&parsed)) }) {
+            Ok(result) => result,
+            Err(err @ AocError::Timeout { .. }) => (Self { duration: timeout, ..Self::failed(name, &err) }, Some(err)),
+            Err(err) => (Self::failed(name, &err), Some(err)),
+        }
+    }
+
+    fn new<D: ToString>(day: D, status: Status) -> Self {
+        Self { name: day.to_string(), day: None, part: None, status, answer: None, expected: None, error: None, reason: None, duration: Duration::ZERO, parse_duration: Duration::ZERO }
+    }
+
+    /// Records a puzzle which failed, or which could not be run at all (such as when its input is missing).
+    pub fn failed<D: ToString>(day: D, err: &AocError) -> Self {
+        let error = match err {
+            AocError::Panicked { message, location: Some(location) } => format!("{message} at {location}"),
+            AocError::Panicked { message, location: None } => message.clone(),
+            err => err.to_string(),
+        };
+
+        Self { error: Some(error), ..Self::new(day, Status::of(err)) }
+    }
+
+    /// Records a puzzle which was skipped rather than run.
+    pub fn skipped<D: ToString>(day: D) -> Self {
+        Self::new(day, Status::Skipped)
+    }
+
+    /// Records a puzzle which wasn't run because it is too slow (see [`Status::Slow`]).
+    pub fn slow<D: ToString>(day: D) -> Self {
+        Self::new(day, Status::Slow)
+    }
+
+    /// Records why the puzzle was skipped or left out.
+    pub fn because<R: ToString>(self, reason: R) -> Self {
+        Self { reason: Some(reason.to_string()), ..self }
+    }
+
+    /// Compares the answer with the one we expected, marking this result as a [`Status::WrongAnswer`] if
+    /// they don't match.
+    pub fn expecting(self, expected: &str) -> Self {
+        match &self.answer {
+            Some(answer) if self.status == Status::Ok && answer != expected => Self { status: Status::WrongAnswer, expected: Some(expected.to_string()), ..self },
+            _ => self,
+        }
+    }
+
+    /// Records which day and part of the puzzle this result was produced for.
+    pub fn for_part(self, day: u8, part: u8) -> Self {
+        Self { day: Some(day), part: Some(part), ..self }
+    }
+
+    /// Records how long it took to parse the input this result was produced from.
+    pub fn with_parse_duration(self, parse_duration: Duration) -> Self {
+        Self { parse_duration, ..self }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn part(&self) -> Option<u8> {
+        self.part
+    }
+
+    pub fn answer(&self) -> Option<&Answer> {
+        self.answer.as_ref()
+    }
+
+    /// The answer we expected, if this result is a [`Status::WrongAnswer`].
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn parse_duration(&self) -> Duration {
+        self.parse_duration
+    }
+}
+
+impl Ord for PuzzleResult {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.name.len().cmp(&other.name.len()) {
+            std::cmp::Ordering::Equal => self.name.cmp(&other.name),
+            ordering => ordering,
+        }
+    }
+}
+
+impl PartialOrd for PuzzleResult {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for PuzzleResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:", self.name)?;
+
+        match (self.status, &self.answer, &self.error) {
+            (Status::Skipped | Status::Slow, _, _) => {
+                let label = if self.status == Status::Slow { "SLOW" } else { "SKIPPED" };
+                return match &self.reason {
+                    Some(reason) => write!(f, " {label}: {reason}"),
+                    None => write!(f, " {label}"),
+                };
+            },
+            (Status::Timeout, _, _) => write!(f, " TIMEOUT")?,
+            (Status::MissingInput, _, Some(err)) => return write!(f, " MISSING INPUT: {err}"),
+            (Status::Panicked, _, Some(err)) => write!(f, " PANICKED: {err}")?,
+            (_, _, Some(err)) => write!(f, " ERROR: {err}")?,
+            (_, Some(answer), _) if answer.is_grid() => {},
+            (_, Some(answer), _) => write!(f, " {answer}")?,
+            (_, None, None) => {},
+        }
+
+        if let Some(expected) = &self.expected {
+            write!(f, " WRONG ANSWER (expected {expected})")?;
+        }
+
+        write!(f, " ({}", Elapsed(self.duration))?;
+
+        if !self.parse_duration.is_zero() {
+            write!(f, " + {} parsing", Elapsed(self.parse_duration))?;
+        }
+
+        write!(f, ")")?;
+
+        if let Some(answer) = &self.answer {
+            if answer.is_grid() {
+                for line in answer.to_string().trim_end().lines() {
+                    write!(f, "\n    {}", line)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a duration in whichever unit keeps it readable, respecting any width and alignment.
+pub struct Elapsed(pub Duration);
+
+impl std::fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 > Duration::from_millis(2) {
+            f.pad(&format!("{}ms", self.0.as_millis()))
+        } else if self.0 > Duration::from_micros(2) {
+            f.pad(&format!("{}µs", self.0.as_micros()))
+        } else {
+            f.pad(&format!("{}ns", self.0.as_nanos()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeout() {
+        let (result, err) = PuzzleResult::measure_with_timeout("Day1.1", Duration::from_millis(20), || Ok(()), |_| -> Result<i64, AocError> {
+            loop {
+                crate::cancel::check()?;
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        });
+
+        assert_eq!(result.status(), Status::Timeout);
+        assert!(matches!(err, Some(AocError::Timeout { .. })));
+        assert_eq!(result.to_string(), "Day1.1: TIMEOUT (20ms)");
+
+        let (result, err) = PuzzleResult::measure_with_timeout("Day1.2", Duration::from_secs(5), || Ok(21), |x| Ok(x * 2));
+        assert_eq!(result.status(), Status::Ok);
+        assert_eq!(result.answer(), Some(&Answer::from(42)));
+        assert!(err.is_none());
+    }
+
+    #[test]
+    fn test_shared() {
+        let (parsed, _) = parse_with_timeout("Day1", Duration::from_secs(5), || Ok(vec![3, 4])).unwrap();
+        let parsed: Arc<Vec<i64>> = Arc::new(parsed);
+
+        let (result, _) = PuzzleResult::measure_parsed_with_timeout("Day1.1", Duration::from_secs(5), parsed.clone(), |x| Ok(x[0] + x[1]));
+        assert_eq!(result.answer(), Some(&Answer::from(7)));
+        let (result, _) = PuzzleResult::measure_parsed_with_timeout("Day1.2", Duration::from_secs(5), parsed, |x| Ok(x[0] * x[1]));
+        assert_eq!(result.answer(), Some(&Answer::from(12)));
+
+        let panicked = parse_with_timeout::<Vec<i64>, _>("Day1", Duration::from_secs(5), || panic!("bad input"));
+        assert!(matches!(panicked, Err(AocError::Panicked { .. })));
+        let slow = parse_with_timeout::<(), _>("Day1", Duration::from_millis(20), || loop { crate::cancel::check()? });
+        assert!(matches!(slow, Err(AocError::Timeout { .. })));
+    }
+
+    #[test]
+    fn test_statuses() {
+        let (result, err) = PuzzleResult::measure_parsed("Day5.1", || Ok(Vec::<u32>::new()), |updates| Ok(updates[0]));
+        assert_eq!(result.status(), Status::Panicked);
+        assert!(result.error().is_some_and(|err| err.contains("index out of bounds") && err.contains(" at helpers/src/results.rs:")));
+        assert!(result.to_string().starts_with("Day5.1: PANICKED: index out of bounds"), "{result}");
+        assert!(matches!(err, Some(AocError::Panicked { .. })));
+
+        let (result, _) = PuzzleResult::measure("Day5.2", || Err::<i64, _>(AocError::solve("no middle page")));
+        assert_eq!(result.status(), Status::Error);
+        assert!(result.to_string().starts_with("Day5.2: ERROR: no middle page ("), "{result}");
+
+        let (result, _) = PuzzleResult::measure("Day1.1", || Ok(11));
+        assert_eq!(result.clone().expecting("11").status(), Status::Ok);
+
+        let result = result.expecting("12");
+        assert_eq!(result.status(), Status::WrongAnswer);
+        assert!(result.to_string().starts_with("Day1.1: 11 WRONG ANSWER (expected 12) ("), "{result}");
+
+        assert_eq!(PuzzleResult::skipped("Day6.2").to_string(), "Day6.2: SKIPPED");
+        assert_eq!(PuzzleResult::skipped("Day6.2").because("unfinished").to_string(), "Day6.2: SKIPPED: unfinished");
+        assert_eq!(PuzzleResult::slow("Day6.2").because("brute force").to_string(), "Day6.2: SLOW: brute force");
+        assert!(!Status::Slow.is_failure());
+    }
+
+    #[test]
+    fn test_container() {
+        let results: ResultContainer = [
+            PuzzleResult::measure("Day1.1", || Ok(11)).0,
+            PuzzleResult::measure("Day1.2", || Ok(31)).0.expecting("32"),
+            PuzzleResult::measure("Day2.1", || Err::<i64, _>(AocError::solve("no reports"))).0,
+            PuzzleResult::skipped("Day2.2"),
+            PuzzleResult::slow("Day2.2").because("brute force"),
+            PuzzleResult::failed("Day3.1", &AocError::MissingInput { year: 2024, day: 3, path: "Day3.txt".to_string() }),
+        ].into_iter().collect();
+
+        assert_eq!(results.failures(), 2);
+
+        let table = results.to_string();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 10, "{table}");
+        assert!(lines[0].starts_with("Puzzle  Status        Answer             "), "{table}");
+        assert!(lines[2].starts_with("Day1.1  ok            11                 "), "{table}");
+        assert!(lines[3].starts_with("Day1.2  wrong answer  31 (expected 32)   "), "{table}");
+        assert!(lines[4].starts_with("Day2.1  error         no reports         "), "{table}");
+        assert_eq!(lines[5], "Day2.2  skipped");
+        assert_eq!(lines[6], "Day2.2  slow          brute force");
+        assert!(lines[7].starts_with("Day3.1  no input      there is no input for 2024 day 3"), "{table}");
+        assert_eq!(lines[9], "1 passed, 2 failed, 1 skipped, 1 slow, 1 missing input(s)");
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Display;
+
+use crate::error::AocError;
+use crate::guesses::Verdict;
+use crate::http::Request;
+use crate::inputs::Http;
+use crate::questions::Answer;
+
+/// What the puzzle site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer was checked, and was either accepted or rejected.
+    Checked(Verdict),
+    /// The answer wasn't checked because another one was given too recently. Holds how long is left to
+    /// wait, if the page said.
+    RateLimited { wait: Option<String> },
+    /// The answer wasn't checked because the part has already been solved (or isn't unlocked yet).
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Works out the outcome from the page returned after submitting an answer.
+    pub fn parse(page: &str) -> Result<Self, AocError> {
+        let text = article(page);
+
+        if text.contains("That's the right answer") {
+            Ok(Outcome::Checked(Verdict::Correct))
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Ok(Outcome::Checked(Verdict::TooHigh))
+            } else if text.contains("your answer is too low") {
+                Ok(Outcome::Checked(Verdict::TooLow))
+            } else {
+                Ok(Outcome::Checked(Verdict::Incorrect))
+            }
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text.split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Ok(Outcome::RateLimited { wait })
+        } else if text.contains("You don't seem to be solving the right level") {
+            Ok(Outcome::WrongLevel)
+        } else {
+            Err(AocError::parse(text.chars().take(100).collect::<String>(), "did not recognise the response to the answer"))
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Checked(verdict) => write!(f, "{verdict}"),
+            Outcome::RateLimited { wait: Some(wait) } => write!(f, "rate limited (try again in {wait})"),
+            Outcome::RateLimited { wait: None } => write!(f, "rate limited"),
+            Outcome::WrongLevel => write!(f, "not checked, since the part is already solved or still locked"),
+        }
+    }
+}
+
+/// Submits the answer for a part and reports what the site made of it.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::guesses::Verdict;
+/// use aoc_helpers::http::{Response, StandIn};
+/// use aoc_helpers::inputs::Http;
+/// use aoc_helpers::questions::Answer;
+/// use aoc_helpers::submit::{submit, Outcome};
+///
+/// let server = StandIn::start(|_| Response::new(200, "<article><p>That's the right answer!</p></article>")).unwrap();
+/// let http = Http::new(server.url(""), "session");
+///
+/// assert_eq!(submit(&http, 2024, 1, 1, &Answer::from(11)).unwrap(), Outcome::Checked(Verdict::Correct));
+/// ```
+pub fn submit(http: &Http, year: u16, day: u8, part: u8, answer: &Answer) -> Result<Outcome, AocError> {
+    let url = http.url(&format!("/{year}/day/{day}/answer"));
+    let answer = answer.to_string();
+    let response = http.send(Request::post_form(&url, &[("level", &part.to_string()), ("answer", answer.trim())]))?;
+
+    if !response.is_success() {
+        return Err(AocError::solve(format!("could not submit to {url}: the site responded with {}", response.status)));
+    }
+
+    Outcome::parse(&response.body)
+}
+
+/// The text of the page's `<article>` (or the whole page if it has none), without any markup.
+fn article(page: &str) -> String {
+    let content = page.split_once("<article").and_then(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article.split_once('>').map_or(article, |(_, body)| body))
+        .unwrap_or(page);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in content.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {},
+        }
+    }
+
+    text.replace("&apos;", "'").replace("&quot;", "\"").replace("&amp;", "&")
+        .split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::{Response, StandIn};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let page = |text: &str| format!("<html><body><main><article><p>{text}</p></article></main></body></html>");
+
+        assert_eq!(Outcome::parse(&page("That's the right answer!  You are one gold star closer.")).unwrap(), Outcome::Checked(Verdict::Correct));
+        assert_eq!(Outcome::parse(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")).unwrap(), Outcome::Checked(Verdict::Incorrect));
+        assert_eq!(Outcome::parse(&page("That's not the right answer; your answer is too high.")).unwrap(), Outcome::Checked(Verdict::TooHigh));
+        assert_eq!(Outcome::parse(&page("That&apos;s not the right answer; your answer is too <em>low</em>.")).unwrap(), Outcome::Checked(Verdict::TooLow));
+        assert_eq!(Outcome::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 39s left to wait.")).unwrap(),
+            Outcome::RateLimited { wait: Some("39s".to_string()) });
+        assert_eq!(Outcome::parse(&page("You don't seem to be solving the right level.  Did you already complete it?")).unwrap(), Outcome::WrongLevel);
+        assert!(Outcome::parse(&page("Something else entirely.")).is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = StandIn::start(|request| match (request.url.as_str(), request.body.as_str()) {
+            ("/2024/day/5/answer", "level=2&answer=143") => Response::new(200, "<article><p>That's the right answer!</p></article>"),
+            ("/2024/day/5/answer", _) => Response::new(200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            _ => Response::new(404, "Not Found"),
+        }).unwrap();
+
+        let http = Http::new(server.url(""), "session");
+        assert_eq!(submit(&http, 2024, 5, 2, &Answer::from(143)).unwrap(), Outcome::Checked(Verdict::Correct));
+        assert_eq!(submit(&http, 2024, 5, 1, &Answer::from(143)).unwrap(), Outcome::Checked(Verdict::TooLow));
+        assert!(submit(&http, 2024, 6, 1, &Answer::from(143)).is_err());
+    }
+}
@@ -0,0 +1,142 @@
+/// Declares an enum of the tiles which can appear in a puzzle's input, each represented by a single character.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::tile;
+///
+/// tile!(pub Tile {
+///     Empty = '.',
+///     Wall = '#',
+/// });
+///
+/// assert_eq!(Tile::try_from('#').unwrap(), Tile::Wall);
+/// assert_eq!(Tile::parse_sequence(".#").unwrap(), vec![Tile::Empty, Tile::Wall]);
+/// assert!(Tile::try_from('?').is_err());
+/// ```
+#[macro_export]
+macro_rules! tile {
+    ($vis:vis $name:ident { $($key:ident = $char:expr,)+ }) => {
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+        $vis enum $name {
+            $($key,)+
+        }
+
+        impl $name {
+            #[allow(dead_code)]
+            pub fn parse_sequence(s: &str) -> Result<Vec<Self>, $crate::error::AocError> {
+                s.chars().enumerate().map(|(i, c)| Self::try_from(c).map_err(|e| e.at(1, i + 1).in_line(s))).collect()
+            }
+
+            const UNRECOGNIZED: &'static str = concat!("not a recognized ", stringify!($name), " (expected one of", $(" ", stringify!($char),)+ ")");
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Self::$key => write!(f, "{}", $char),)+
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::error::AocError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.chars().next() {
+                    $(Some($char) => Ok(Self::$key),)+
+                    None => Err($crate::error::AocError::parse("", Self::UNRECOGNIZED)),
+                    _ => Err($crate::error::AocError::parse(s, Self::UNRECOGNIZED)),
+                }
+            }
+        }
+
+        impl TryFrom<char> for $name {
+            type Error = $crate::error::AocError;
+
+            fn try_from(value: char) -> Result<Self, Self::Error> {
+                match value {
+                    $($char => Ok(Self::$key),)+
+                    _ => Err($crate::error::AocError::parse(value, Self::UNRECOGNIZED)),
+                }
+            }
+        }
+    };
+}
+
+/// Declares a two-dimensional grid of tiles, backed by a [`RectVec`](crate::RectVec), which can be
+/// parsed from a puzzle's input. The tiles can either be declared separately with [`tile!`], or inline.
+///
+/// Parsing reports the row and column of any character which is not a recognized tile.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::grid;
+///
+/// grid!(pub Map<Tile> {
+///     Empty = '.',
+///     Wall = '#',
+/// } => {
+///     pub fn walls(&self) -> usize {
+///         self.0.iter().flatten().filter(|t| **t == Tile::Wall).count()
+///     }
+/// });
+///
+/// let map: Map = "..#\n#..".parse().unwrap();
+/// assert_eq!(map.get(2, 0), Some(Tile::Wall));
+/// assert_eq!(map.walls(), 2);
+///
+/// let err = "..#\n#.?".parse::<Map>().unwrap_err();
+/// assert_eq!(err.location(), (Some(2), Some(3)));
+/// ```
+#[macro_export]
+macro_rules! grid {
+    ($vis:vis $name:ident <$tile:ty> $(=> { $($impl:tt)* })?) => {
+        #[derive(Debug, PartialEq, Eq, Clone, Hash)]
+        $vis struct $name($crate::vecs::RectVec<$tile>);
+
+        impl $name {
+            #[allow(dead_code)]
+            pub fn get(&self, x: usize, y: usize) -> Option<$tile> {
+                self.0.get(y, x).copied()
+            }
+
+            #[allow(dead_code)]
+            pub fn set(&mut self, x: usize, y: usize, tile: $tile) {
+                self.0.set(y, x, tile);
+            }
+
+            $($($impl)*)?
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                writeln!(f, "{}", self.0)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::error::AocError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let map = s.trim()
+                    .lines()
+                    .enumerate()
+                    .map(|(row, line)| {
+                        let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+                        line.trim().chars()
+                            .enumerate()
+                            .map(|(col, c)| <$tile>::try_from(c).map_err(|e| e.at(row + 1, indent + col + 1).in_line(line)))
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Self(map.into()))
+            }
+        }
+    };
+
+    ($vis:vis $name:ident <$tile:ident> { $($key:ident = $char:expr,)+ } $(=> { $($impl:tt)* })?) => {
+        $crate::tile!($vis $tile { $($key = $char,)+ });
+        $crate::grid!($vis $name <$tile> $(=> { $($impl)* })?);
+    };
+}
\ No newline at end of file
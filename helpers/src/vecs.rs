@@ -1,5 +1,23 @@
 use std::fmt::Display;
 
+/// A rectangular, row-major grid of values stored in a single contiguous vector.
+///
+/// # Examples
+/// ```
+/// use aoc_helpers::RectVec;
+///
+/// let mut grid = RectVec::from(vec![
+///     vec![1, 2, 3],
+///     vec![4, 5, 6],
+/// ]);
+///
+/// assert_eq!((grid.rows(), grid.cols()), (2, 3));
+/// assert_eq!(grid.get(1, 2), Some(&6));
+/// assert_eq!(grid.get(0, 3), None);
+///
+/// grid[(0, 0)] = 7;
+/// assert_eq!(grid.row(0), &[7, 2, 3]);
+/// ```
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
 pub struct RectVec<T>(Vec<T>, usize, usize);
 
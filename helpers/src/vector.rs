@@ -29,11 +29,11 @@ impl<T> Vector<T, 3>
     /// 
     /// # Examples
     /// ```
-    /// use aoc2023::helpers::matrix::Vector;
+    /// use aoc_helpers::vector::Vector;
     /// 
-    /// let vector: Vector<_, _> = [1, 2, 3].into();
-    /// let cross_matrix = vector.cross_matrix();
-    /// assert_eq!(cross_matrix.into(), [[0, -3, 2], [3, 0, -1], [-2, 1, 0]]);
+    /// let vector: Vector<_, 3> = [1, 2, 3].into();
+    /// let cross_matrix: [[i32; 3]; 3] = vector.cross_matrix().into();
+    /// assert_eq!(cross_matrix, [[0, -3, 2], [3, 0, -1], [-2, 1, 0]]);
     /// ```
     pub fn cross_matrix(&self) -> Matrix<T, 3, 3> {
         [
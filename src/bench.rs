@@ -0,0 +1,202 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use aoc_helpers::error::AocError;
+use aoc_helpers::results::Elapsed;
+
+/// Controls how many times each benchmark is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// The number of untimed runs used to warm up caches and the branch predictor.
+    pub warmup: usize,
+    /// The number of timed runs used to calculate the statistics.
+    pub iterations: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { warmup: 10, iterations: 100 }
+    }
+}
+
+/// Summary statistics for the samples collected by a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Calculates the statistics for a set of samples, returning `None` if there are none.
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+        let runs = samples.len();
+
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+
+        // Nearest-rank percentile, so that p95 is always one of the observed samples.
+        let p95 = samples[(runs * 95).div_ceil(100) - 1];
+
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / runs as f64;
+        let variance = samples.iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>() / runs as f64;
+
+        Some(Self {
+            runs,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Runs `run` repeatedly, discarding the warm-up runs, and summarizes how long each timed run took. Each
+/// run reports how long it took itself, so that it can be timed on its own thread (within a time budget),
+/// and the first one to fail stops the benchmark.
+pub fn measure<F>(options: Options, mut run: F) -> Result<Stats, AocError>
+    where F: FnMut() -> Result<Duration, AocError>
+{
+    for _ in 0..options.warmup {
+        run()?;
+    }
+
+    let mut samples = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations.max(1) {
+        samples.push(run()?);
+    }
+
+    Ok(Stats::from_samples(&mut samples).expect("at least one sample is always collected"))
+}
+
+/// A comparison table of the benchmarks which have been run, in the order they were added, including any
+/// which failed.
+#[derive(Debug, Default)]
+pub struct Report(Vec<(String, Result<Stats, String>)>);
+
+impl Report {
+    pub fn add<N: ToString>(&mut self, name: N, stats: Stats) {
+        self.0.push((name.to_string(), Ok(stats)));
+    }
+
+    /// Records a benchmark which couldn't be completed, such as one which panicked or timed out.
+    pub fn fail<N: ToString>(&mut self, name: N, err: &AocError) {
+        self.0.push((name.to_string(), Err(err.to_string())));
+    }
+
+    /// The number of benchmarks which couldn't be completed.
+    pub fn failures(&self) -> usize {
+        self.0.iter().filter(|(_, stats)| stats.is_err()).count()
+    }
+
+    /// The benchmarks which were completed.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Stats)> {
+        self.0.iter().filter_map(|(name, stats)| Some((name.as_str(), stats.as_ref().ok()?)))
+    }
+
+    /// The sum of the median run times, which is the best estimate of how long a full run takes.
+    pub fn total(&self) -> Duration {
+        self.iter().map(|(_, stats)| stats.median).sum()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.0.iter().map(|(name, _)| name.len()).max().unwrap_or(0).max("Total".len());
+        let total = self.total();
+
+        writeln!(f, "{:<width$}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}  {:>6}", "Name", "min", "median", "mean", "p95", "stddev", "share")?;
+        writeln!(f, "{}", "-".repeat(width + 6 * 11 - 3))?;
+
+        for (name, stats) in self.0.iter() {
+            let stats = match stats {
+                Ok(stats) => stats,
+                Err(err) => {
+                    writeln!(f, "{name:<width$}  failed: {err}")?;
+                    continue;
+                },
+            };
+
+            let share = if total.is_zero() { 0.0 } else { stats.median.as_secs_f64() / total.as_secs_f64() * 100.0 };
+
+            writeln!(f, "{:<width$}  {:>9}  {:>9}  {:>9}  {:>9}  {:>9}  {:>5.1}%",
+                name,
+                Elapsed(stats.min),
+                Elapsed(stats.median),
+                Elapsed(stats.mean),
+                Elapsed(stats.p95),
+                Elapsed(stats.stddev),
+                share)?;
+        }
+
+        writeln!(f, "{}", "-".repeat(width + 6 * 11 - 3))?;
+        write!(f, "{:<width$}  {:>9}  {:>9}", "Total", "", Elapsed(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&us| Duration::from_micros(us)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&mut micros(&[5, 1, 4, 2, 3])).unwrap();
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.stddev.as_nanos(), 1414);
+
+        let stats = Stats::from_samples(&mut micros(&[4, 1, 3, 2])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+
+        let mut samples = micros(&(1..=100).collect::<Vec<_>>());
+        assert_eq!(Stats::from_samples(&mut samples).unwrap().p95, Duration::from_micros(95));
+
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let stats = measure(Options { warmup: 3, iterations: 5 }, || { calls += 1; Ok(Duration::from_micros(calls)) }).unwrap();
+        assert_eq!(calls, 8);
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_micros(4));
+
+        let mut calls = 0;
+        assert!(measure(Options::default(), || { calls += 1; Err(AocError::solve("nope")) }).is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_report() {
+        let mut report = Report::default();
+        report.add("Day5.1", Stats::from_samples(&mut micros(&[10])).unwrap());
+        report.fail("Day6.1", &AocError::Timeout { limit: Duration::from_secs(10) });
+
+        assert_eq!(report.iter().map(|(name, _)| name).collect::<Vec<_>>(), ["Day5.1"]);
+        assert_eq!(report.total(), Duration::from_micros(10));
+        assert_eq!(report.failures(), 1);
+        assert!(report.to_string().lines().any(|line| line.starts_with("Day6.1  failed: ")), "{report}");
+    }
+}
@@ -0,0 +1,567 @@
+use std::path::PathBuf;
+
+use aoc_helpers::filter::{Filter, Only};
+use aoc_helpers::guesses::Verdict;
+use aoc_helpers::questions::Answer;
+
+use crate::{bench, export, history};
+
+pub const USAGE: &str = r#"Usage: aoc <command> [options]
+
+Commands:
+  run     Runs a day's solution against its input and prints the answers.
+            --year <YYYY>     The event year to run (default: the latest registered year).
+            --day <N>         The day to run (required).
+            --part <1|2>      Only run the given part (default: both).
+            --input <PATH>    The input file to use (default: inputs/<YYYY>/Day<N>.txt).
+            --examples        Checks the day's examples instead of running its input.
+  test    Runs the examples and real inputs for a year (checking them against the known answers) and
+          prints a table of the results, like the 'solutions' test target.
+            --year <YYYY>     The event year to run (default: the latest registered year).
+            --days <N|A-B>    Only run the given day or range of days (default: every day).
+            --part <1|2>      Only run the given part (default: both).
+            --examples        Only run the examples.
+            --real            Only run the real inputs.
+            --include-slow    Also run the real inputs of days marked as slow.
+  bench   Runs each solution repeatedly and prints a table of timing statistics.
+            --year <YYYY>     The event year to benchmark (default: the latest registered year).
+            --day <N>         Only benchmark the given day (default: every day with an input).
+            --part <1|2>      Only benchmark the given part (default: both).
+            --iterations <N>  The number of timed runs (default: 100).
+            --warmup <N>      The number of untimed warm-up runs (default: 10).
+            --history <PATH>  The file that timings are recorded in (default: bench-history.tsv).
+            --no-history      Don't record timings or compare them with previous runs.
+            --threshold <PCT> How much slower than its historical median a part can get before it is
+                              flagged as a regression (default: 10).
+  export  Runs every day's solution and writes the results in a machine-readable format.
+            --year <YYYY>     The event year to run (default: the latest registered year).
+            --day <N>         Only run the given day (default: every registered day).
+            --part <1|2>      Only run the given part (default: both).
+            --format <FORMAT> One of json, csv or junit (default: json).
+            --output <PATH>   The file to write the results to (default: standard output).
+            --include-slow    Also run the days marked as slow.
+  readme  Runs every day's solution and rewrites the results table in the README.
+            --year <YYYY>     The event year to run (default: the latest registered year).
+            --path <PATH>     The README to update (default: README.md).
+            --include-slow    Also run the days marked as slow.
+  record  Runs every day's solution and records its answers as the known answers for the year, which
+          later runs are checked against.
+            --year <YYYY>     The event year to run (default: the latest registered year).
+            --day <N>         Only record the given day (default: every registered day).
+            --part <1|2>      Only record the given part (default: both).
+            --include-slow    Also run the days marked as slow.
+  guess   Records the verdict for an answer which was given for a part, then lists the guesses made so
+          far. 'run' warns about answers which repeat a wrong guess or fall outside the known bounds.
+            --year <YYYY>     The event year (default: the latest registered year).
+            --day <N>         The day the guess was for (required).
+            --part <1|2>      The part the guess was for (required when recording a guess).
+            --answer <VALUE>  The answer which was given.
+            --verdict <V>     One of correct, incorrect, too-high or too-low.
+  submit  Runs a part against its input and submits the answer to the puzzle site (which needs
+          AOC_SESSION to be set). Accepted answers are recorded as known answers, and every verdict is
+          added to the guess log.
+            --year <YYYY>     The event year (default: the latest registered year).
+            --day <N>         The day to submit (required).
+            --part <1|2>      The part to submit (required).
+            --force           Submits even if the guess log says the answer must be wrong.
+  new     Generates a new day from the year's day template and registers it with the year. Refuses to
+          overwrite a day which already exists.
+            --year <YYYY>     The event year (default: the latest registered year).
+            --day <N>         The day to generate (required).
+            --example <PATH>  A file holding the example input to validate against ('-' reads it from
+                              standard input).
+            --part1 <VALUE>   The answer the example should produce for part 1.
+            --part2 <VALUE>   The answer the example should produce for part 2.
+  watch   Watches a day's source and input files, rebuilding and re-running its examples and real input
+          whenever they change, and prints a compact summary with a diff of any wrong answers.
+            --year <YYYY>     The event year (default: the latest registered year).
+            --day <N>         The day to watch (required).
+            --once            Runs the day once and prints the summary without watching (this is what
+                              each re-run uses after rebuilding).
+  list    Lists the days which have been implemented for each year.
+  help    Prints this message.
+
+During development, run 'aoc watch --day <N>' to re-run a single day as you make changes, or
+'cargo watch -x test' to run all of the solutions."#;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        year: Option<u16>,
+        day: u8,
+        part: Option<u8>,
+        input: Option<PathBuf>,
+        examples: bool,
+    },
+    Test {
+        year: Option<u16>,
+        filter: Filter,
+    },
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        part: Option<u8>,
+        options: bench::Options,
+        history: Option<PathBuf>,
+        threshold: u32,
+    },
+    Export {
+        year: Option<u16>,
+        day: Option<u8>,
+        part: Option<u8>,
+        format: export::Format,
+        output: Option<PathBuf>,
+        include_slow: bool,
+    },
+    Readme {
+        year: Option<u16>,
+        path: PathBuf,
+        include_slow: bool,
+    },
+    Record {
+        year: Option<u16>,
+        day: Option<u8>,
+        part: Option<u8>,
+        include_slow: bool,
+    },
+    Guess {
+        year: Option<u16>,
+        day: u8,
+        part: Option<u8>,
+        guess: Option<(Answer, Verdict)>,
+    },
+    Submit {
+        year: Option<u16>,
+        day: u8,
+        part: u8,
+        force: bool,
+    },
+    New {
+        year: Option<u16>,
+        day: u8,
+        example: Option<PathBuf>,
+        part1: Option<String>,
+        part2: Option<String>,
+    },
+    Watch {
+        year: Option<u16>,
+        day: u8,
+        once: bool,
+    },
+    List,
+    Help,
+}
+
+impl Command {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut args = args.into_iter();
+
+        match args.next().as_deref() {
+            Some("run") => {
+                let mut year = None;
+                let mut day = None;
+                let mut part = None;
+                let mut input = None;
+                let mut examples = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--day" | "-d" => day = Some(parse_value(&arg, args.next())?),
+                        "--part" | "-p" => part = Some(parse_part(&arg, args.next())?),
+                        "--input" | "-i" => input = Some(PathBuf::from(args.next().ok_or(format!("'{arg}' requires a value."))?)),
+                        "--examples" | "-e" => examples = true,
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'run'.")),
+                    }
+                }
+
+                Ok(Command::Run {
+                    year,
+                    day: day.ok_or("The 'run' command requires a '--day'.")?,
+                    part,
+                    input,
+                    examples,
+                })
+            },
+            Some("test") => {
+                let mut year = None;
+                let mut filter = Filter::default();
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--days" | "--day" | "-d" => filter.days = parse_value(&arg, args.next())?,
+                        "--part" | "-p" => filter.part = Some(parse_part(&arg, args.next())?),
+                        "--examples" | "-e" if filter.only != Only::Real => filter.only = Only::Examples,
+                        "--real" | "-r" if filter.only != Only::Examples => filter.only = Only::Real,
+                        "--examples" | "-e" | "--real" | "-r" => return Err("Only one of '--examples' and '--real' can be used.".to_string()),
+                        "--include-slow" => filter.include_slow = true,
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'test'.")),
+                    }
+                }
+
+                Ok(Command::Test { year, filter })
+            },
+            Some("bench") => {
+                let mut year = None;
+                let mut day = None;
+                let mut part = None;
+                let mut options = bench::Options::default();
+                let mut history = Some(PathBuf::from(history::DEFAULT_PATH));
+                let mut threshold = 10;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--day" | "-d" => day = Some(parse_value(&arg, args.next())?),
+                        "--part" | "-p" => part = Some(parse_part(&arg, args.next())?),
+                        "--iterations" | "-n" => options.iterations = parse_value(&arg, args.next())?,
+                        "--warmup" | "-w" => options.warmup = parse_value(&arg, args.next())?,
+                        "--history" => history = Some(PathBuf::from(args.next().ok_or(format!("'{arg}' requires a value."))?)),
+                        "--no-history" => history = None,
+                        "--threshold" | "-t" => threshold = parse_value(&arg, args.next())?,
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'bench'.")),
+                    }
+                }
+
+                if options.iterations == 0 {
+                    return Err("The 'bench' command requires at least one iteration.".to_string());
+                }
+
+                Ok(Command::Bench { year, day, part, options, history, threshold })
+            },
+            Some("export") => {
+                let mut year = None;
+                let mut day = None;
+                let mut part = None;
+                let mut format = export::Format::Json;
+                let mut output = None;
+                let mut include_slow = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--day" | "-d" => day = Some(parse_value(&arg, args.next())?),
+                        "--part" | "-p" => part = Some(parse_part(&arg, args.next())?),
+                        "--format" | "-f" => format = args.next().ok_or(format!("'{arg}' requires a value."))?.parse()?,
+                        "--output" | "-o" => output = Some(PathBuf::from(args.next().ok_or(format!("'{arg}' requires a value."))?)),
+                        "--include-slow" => include_slow = true,
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'export'.")),
+                    }
+                }
+
+                Ok(Command::Export { year, day, part, format, output, include_slow })
+            },
+            Some("readme") => {
+                let mut year = None;
+                let mut path = PathBuf::from("README.md");
+                let mut include_slow = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--path" => path = PathBuf::from(args.next().ok_or(format!("'{arg}' requires a value."))?),
+                        "--include-slow" => include_slow = true,
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'readme'.")),
+                    }
+                }
+
+                Ok(Command::Readme { year, path, include_slow })
+            },
+            Some("record") => {
+                let mut year = None;
+                let mut day = None;
+                let mut part = None;
+                let mut include_slow = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--day" | "-d" => day = Some(parse_value(&arg, args.next())?),
+                        "--part" | "-p" => part = Some(parse_part(&arg, args.next())?),
+                        "--include-slow" => include_slow = true,
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'record'.")),
+                    }
+                }
+
+                Ok(Command::Record { year, day, part, include_slow })
+            },
+            Some("guess") => {
+                let mut year = None;
+                let mut day = None;
+                let mut part = None;
+                let mut answer = None;
+                let mut verdict = None;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--day" | "-d" => day = Some(parse_value(&arg, args.next())?),
+                        "--part" | "-p" => part = Some(parse_part(&arg, args.next())?),
+                        "--answer" | "-a" => answer = Some(args.next().ok_or(format!("'{arg}' requires a value."))?),
+                        "--verdict" | "-v" => verdict = Some(parse_value::<Verdict>(&arg, args.next())?),
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'guess'.")),
+                    }
+                }
+
+                let guess = match (answer, verdict) {
+                    // Numbers are read as numbers, so that they can be checked against the known bounds.
+                    (Some(answer), Some(verdict)) if part.is_some() => Some((Answer::from(answer), verdict)),
+                    (None, None) => None,
+                    _ => return Err("Recording a guess requires a '--part', '--answer' and '--verdict'.".to_string()),
+                };
+
+                Ok(Command::Guess {
+                    year,
+                    day: day.ok_or("The 'guess' command requires a '--day'.")?,
+                    part,
+                    guess,
+                })
+            },
+            Some("submit") => {
+                let mut year = None;
+                let mut day = None;
+                let mut part = None;
+                let mut force = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--day" | "-d" => day = Some(parse_value(&arg, args.next())?),
+                        "--part" | "-p" => part = Some(parse_part(&arg, args.next())?),
+                        "--force" | "-f" => force = true,
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'submit'.")),
+                    }
+                }
+
+                Ok(Command::Submit {
+                    year,
+                    day: day.ok_or("The 'submit' command requires a '--day'.")?,
+                    part: part.ok_or("The 'submit' command requires a '--part'.")?,
+                    force,
+                })
+            },
+            Some("new") => {
+                let mut year = None;
+                let mut day = None;
+                let mut example = None;
+                let mut part1 = None;
+                let mut part2 = None;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--day" | "-d" => day = Some(parse_value(&arg, args.next())?),
+                        "--example" | "-e" => example = Some(PathBuf::from(args.next().ok_or(format!("'{arg}' requires a value."))?)),
+                        "--part1" => part1 = Some(args.next().ok_or(format!("'{arg}' requires a value."))?),
+                        "--part2" => part2 = Some(args.next().ok_or(format!("'{arg}' requires a value."))?),
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'new'.")),
+                    }
+                }
+
+                Ok(Command::New {
+                    year,
+                    day: day.ok_or("The 'new' command requires a '--day'.")?,
+                    example,
+                    part1,
+                    part2,
+                })
+            },
+            Some("watch") => {
+                let mut year = None;
+                let mut day = None;
+                let mut once = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--year" | "-y" => year = Some(parse_value(&arg, args.next())?),
+                        "--day" | "-d" => day = Some(parse_value(&arg, args.next())?),
+                        "--once" => once = true,
+                        _ => return Err(format!("'{arg}' is not a recognized option for 'watch'.")),
+                    }
+                }
+
+                Ok(Command::Watch {
+                    year,
+                    day: day.ok_or("The 'watch' command requires a '--day'.")?,
+                    once,
+                })
+            },
+            Some("list") => Ok(Command::List),
+            Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+            Some(other) => Err(format!("'{other}' is not a recognized command.")),
+        }
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(arg: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or(format!("'{arg}' requires a value."))?;
+    value.parse().map_err(|_| format!("'{value}' is not a valid value for '{arg}'."))
+}
+
+fn parse_part(arg: &str, value: Option<String>) -> Result<u8, String> {
+    match parse_value(arg, value)? {
+        part @ (1 | 2) => Ok(part),
+        part => Err(format!("'{part}' is not a valid part, expected 1 or 2.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_helpers::guesses::{GuessLog, Warning};
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        Command::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(parse(&["run", "--year", "2023", "--day", "6", "--part", "2", "--input", "in.txt"]), Ok(Command::Run {
+            year: Some(2023),
+            day: 6,
+            part: Some(2),
+            input: Some(PathBuf::from("in.txt")),
+            examples: false,
+        }));
+
+        assert_eq!(parse(&["run", "-d", "3", "--examples"]), Ok(Command::Run { year: None, day: 3, part: None, input: None, examples: true }));
+    }
+
+    #[test]
+    fn test_parse_test() {
+        assert_eq!(parse(&["test"]), Ok(Command::Test { year: None, filter: Filter::default() }));
+        assert_eq!(parse(&["test", "--days", "3-7", "-p", "2", "--real", "--include-slow"]), Ok(Command::Test {
+            year: None,
+            filter: Filter { days: "3-7".parse().unwrap(), part: Some(2), only: Only::Real, include_slow: true },
+        }));
+
+        assert!(parse(&["test", "--days", "7-3"]).is_err());
+        assert!(parse(&["test", "--examples", "--real"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(parse(&["bench"]), Ok(Command::Bench {
+            year: None,
+            day: None,
+            part: None,
+            options: bench::Options::default(),
+            history: Some(PathBuf::from(history::DEFAULT_PATH)),
+            threshold: 10,
+        }));
+
+        assert_eq!(parse(&["bench", "-d", "7", "-p", "1", "-n", "20", "-w", "0", "--no-history", "-t", "25"]), Ok(Command::Bench {
+            year: None,
+            day: Some(7),
+            part: Some(1),
+            options: bench::Options { warmup: 0, iterations: 20 },
+            history: None,
+            threshold: 25,
+        }));
+
+        assert!(parse(&["bench", "--iterations", "0"]).is_err());
+        assert!(parse(&["bench", "--examples"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_export() {
+        assert_eq!(parse(&["export"]), Ok(Command::Export { year: None, day: None, part: None, format: export::Format::Json, output: None, include_slow: false }));
+        assert_eq!(parse(&["export", "-y", "2024", "--format", "junit", "-o", "results.xml"]), Ok(Command::Export {
+            year: Some(2024),
+            day: None,
+            part: None,
+            format: export::Format::Junit,
+            output: Some(PathBuf::from("results.xml")),
+            include_slow: false,
+        }));
+        assert_eq!(parse(&["export", "-d", "5", "--include-slow"]), Ok(Command::Export {
+            year: None,
+            day: Some(5),
+            part: None,
+            format: export::Format::Json,
+            output: None,
+            include_slow: true,
+        }));
+
+        assert!(parse(&["export", "--format", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_readme() {
+        assert_eq!(parse(&["readme", "--path", "docs/README.md"]), Ok(Command::Readme { year: None, path: PathBuf::from("docs/README.md"), include_slow: false }));
+        assert_eq!(parse(&["readme", "--include-slow"]), Ok(Command::Readme { year: None, path: PathBuf::from("README.md"), include_slow: true }));
+    }
+
+    #[test]
+    fn test_parse_record() {
+        assert_eq!(parse(&["record", "-d", "5"]), Ok(Command::Record { year: None, day: Some(5), part: None, include_slow: false }));
+        assert_eq!(parse(&["record", "--include-slow"]), Ok(Command::Record { year: None, day: None, part: None, include_slow: true }));
+    }
+
+    #[test]
+    fn test_parse_guess() {
+        assert_eq!(parse(&["guess", "-d", "5", "-p", "1", "-a", "5000", "-v", "too-high"]), Ok(Command::Guess {
+            year: None,
+            day: 5,
+            part: Some(1),
+            guess: Some((Answer::from(5000), Verdict::TooHigh)),
+        }));
+        assert_eq!(parse(&["guess", "-d", "5"]), Ok(Command::Guess { year: None, day: 5, part: None, guess: None }));
+        assert!(parse(&["guess", "-d", "5", "-a", "5000", "-v", "too-high"]).is_err());
+        assert!(parse(&["guess", "-d", "5", "-p", "1", "-a", "5000", "-v", "warm"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_guess_bounds() {
+        let Ok(Command::Guess { guess: Some((answer, _)), .. }) = parse(&["guess", "-d", "5", "-p", "1", "-a", "5000", "-v", "incorrect"]) else {
+            panic!("expected a guess to be parsed");
+        };
+        assert_eq!(answer.as_integer(), Some(5000));
+
+        let path = std::env::temp_dir().join(format!("aoc-cli-guesses-{}", std::process::id())).join("guesses.tsv");
+        let mut log = GuessLog::load(&path).unwrap();
+        log.record(5, 1, &Answer::from(4000), Verdict::TooHigh).unwrap();
+        let warnings = log.check(5, 1, &answer);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(warnings, [Warning::TooHigh { bound: "4000".to_string() }]);
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        assert_eq!(parse(&["submit", "-d", "5", "-p", "2", "--force"]), Ok(Command::Submit { year: None, day: 5, part: 2, force: true }));
+        assert!(parse(&["submit", "-d", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(parse(&["new", "-d", "8", "-e", "-", "--part1", "14"]), Ok(Command::New {
+            year: None,
+            day: 8,
+            example: Some(PathBuf::from("-")),
+            part1: Some("14".to_string()),
+            part2: None,
+        }));
+        assert!(parse(&["new", "--part1", "14"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        assert_eq!(parse(&["watch", "-d", "5"]), Ok(Command::Watch { year: None, day: 5, once: false }));
+        assert_eq!(parse(&["watch", "-y", "2024", "-d", "5", "--once"]), Ok(Command::Watch { year: Some(2024), day: 5, once: true }));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(&["run"]).is_err());
+        assert!(parse(&["run", "--day"]).is_err());
+        assert!(parse(&["run", "--day", "x"]).is_err());
+        assert!(parse(&["run", "--year", "24", "--day", "1"]).is_ok());
+        assert!(parse(&["run", "--year", "next", "--day", "1"]).is_err());
+        assert!(parse(&["run", "--day", "1", "--part", "3"]).is_err());
+        assert!(parse(&["frobnicate"]).is_err());
+        assert_eq!(parse(&[]), Ok(Command::Help));
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc_helpers::results::{PuzzleResult, Status};
+
+/// The machine-readable formats which results can be exported in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+    Junit,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "junit" | "xml" => Ok(Format::Junit),
+            other => Err(format!("'{other}' is not a recognized format, expected json, csv or junit.")),
+        }
+    }
+}
+
+impl Format {
+    /// Renders the results for the given year in this format.
+    pub fn render(self, year: u16, results: &[PuzzleResult]) -> String {
+        match self {
+            Format::Json => json(year, results),
+            Format::Csv => csv(year, results),
+            Format::Junit => junit(year, results),
+        }
+    }
+}
+
+/// Renders the results as a JSON array with one object per part.
+pub fn json(year: u16, results: &[PuzzleResult]) -> String {
+    let mut out = String::from("[");
+
+    for (i, result) in results.iter().enumerate() {
+        out.push_str(if i == 0 { "\n" } else { ",\n" });
+        write!(out, "  {{\"year\": {year}, \"day\": {}, \"part\": {}, \"answer\": {}, \"status\": {}, \"duration_ms\": {}, \"parse_duration_ms\": {}, \"error\": {}}}",
+            json_option(result.day()),
+            json_option(result.part()),
+            json_string(result.answer().map(|answer| answer.to_string())),
+            json_string(Some(result.status().to_string())),
+            millis(result.duration()),
+            millis(result.parse_duration()),
+            json_string(result.error())).unwrap();
+    }
+
+    out.push_str(if results.is_empty() { "]\n" } else { "\n]\n" });
+    out
+}
+
+/// Renders the results as CSV, with a header row and one row per part.
+pub fn csv(year: u16, results: &[PuzzleResult]) -> String {
+    let mut out = String::from("year,day,part,answer,status,duration_ms,parse_duration_ms,error\n");
+
+    for result in results {
+        writeln!(out, "{year},{},{},{},{},{},{},{}",
+            result.day().map(|day| day.to_string()).unwrap_or_default(),
+            result.part().map(|part| part.to_string()).unwrap_or_default(),
+            csv_field(&result.answer().map(|answer| answer.to_string()).unwrap_or_default()),
+            result.status(),
+            millis(result.duration()),
+            millis(result.parse_duration()),
+            csv_field(result.error().unwrap_or_default())).unwrap();
+    }
+
+    out
+}
+
+/// Renders the results as a JUnit XML report, with a test case per part, so that CI systems can show them.
+/// Wrong answers are reported as failures, while errors, panics and timeouts are reported as errors, and
+/// days without an input are reported as skipped.
+pub fn junit(year: u16, results: &[PuzzleResult]) -> String {
+    let count = |statuses: &[Status]| results.iter().filter(|result| statuses.contains(&result.status())).count();
+    let failures = count(&[Status::WrongAnswer]);
+    let errors = count(&[Status::Error, Status::Panicked, Status::Timeout]);
+    let skipped = count(&[Status::Skipped, Status::Slow, Status::MissingInput]);
+    let total: Duration = results.iter().map(|result| result.duration() + result.parse_duration()).sum();
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(out, "<testsuites name=\"aoc\" tests=\"{}\" failures=\"{failures}\" errors=\"{errors}\" skipped=\"{skipped}\" time=\"{}\">", results.len(), seconds(total)).unwrap();
+    writeln!(out, "  <testsuite name=\"aoc{year}\" tests=\"{}\" failures=\"{failures}\" errors=\"{errors}\" skipped=\"{skipped}\" time=\"{}\">", results.len(), seconds(total)).unwrap();
+
+    for result in results {
+        let class = match result.day() {
+            Some(day) => format!("aoc{year}.Day{day}"),
+            None => format!("aoc{year}"),
+        };
+
+        write!(out, "    <testcase classname=\"{}\" name=\"{}\" time=\"{}\"",
+            xml_escape(&class),
+            xml_escape(result.name()),
+            seconds(result.duration() + result.parse_duration())).unwrap();
+
+        let detail = match (result.status(), result.answer(), result.error()) {
+            (Status::Skipped | Status::Slow, _, _) => Some(match result.reason() {
+                Some(reason) => format!("<skipped message=\"{}\"/>", xml_escape(reason)),
+                None => "<skipped/>".to_string(),
+            }),
+            (Status::MissingInput, _, error) => Some(format!("<skipped message=\"{}\"/>", xml_escape(error.unwrap_or_default()))),
+            (Status::WrongAnswer, Some(answer), _) => Some(format!("<failure message=\"{}\"/>",
+                xml_escape(&format!("got {answer}, expected {}", result.expected().unwrap_or_default())))),
+            (status, _, Some(error)) => Some(format!("<error type=\"{status}\" message=\"{}\"/>", xml_escape(error))),
+            (_, Some(answer), None) => Some(format!("<system-out>{}</system-out>", xml_escape(&answer.to_string()))),
+            (_, None, None) => None,
+        };
+
+        match detail {
+            Some(detail) => {
+                writeln!(out, ">").unwrap();
+                writeln!(out, "      {detail}").unwrap();
+                writeln!(out, "    </testcase>").unwrap();
+            },
+            None => writeln!(out, "/>").unwrap(),
+        }
+    }
+
+    writeln!(out, "  </testsuite>").unwrap();
+    writeln!(out, "</testsuites>").unwrap();
+    out
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+fn seconds(duration: Duration) -> String {
+    format!("{:.6}", duration.as_secs_f64())
+}
+
+fn json_option<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_else(|| "null".to_string())
+}
+
+fn json_string<S: AsRef<str>>(value: Option<S>) -> String {
+    let Some(value) = value else {
+        return "null".to_string();
+    };
+
+    let mut out = String::from("\"");
+    for c in value.as_ref().chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn xml_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_helpers::error::AocError;
+
+    use super::*;
+
+    fn results() -> Vec<PuzzleResult> {
+        vec![
+            PuzzleResult::measure("Day1.1", || Ok(42)).0.for_part(1, 1),
+            PuzzleResult::measure("Day1.2", || Err::<i64, _>(AocError::solve("no \"path\", <sorry>"))).0.for_part(1, 2),
+            PuzzleResult::measure("Day2.1", || Ok(7)).0.for_part(2, 1).expecting("8"),
+            PuzzleResult::skipped("Day2.2").for_part(2, 2),
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        let json = json(2024, &results());
+        assert!(json.starts_with("[\n  {\"year\": 2024, \"day\": 1, \"part\": 1, \"answer\": \"42\", \"status\": \"ok\", \"duration_ms\": "), "{json}");
+        assert!(json.contains("\"answer\": null, \"status\": \"error\""), "{json}");
+        assert!(json.contains("\"error\": \"no \\\"path\\\", <sorry>\"},\n"), "{json}");
+        assert!(json.contains("\"status\": \"skipped\", \"duration_ms\": 0.000, \"parse_duration_ms\": 0.000, \"error\": null}\n]"), "{json}");
+
+        assert_eq!(super::json(2024, &[]), "[]\n");
+        assert_eq!(json_string(Some("a\nb\u{1}")), "\"a\\nb\\u0001\"");
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = csv(2024, &results());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "year,day,part,answer,status,duration_ms,parse_duration_ms,error");
+        assert!(lines[1].starts_with("2024,1,1,42,ok,"), "{csv}");
+        assert!(lines[2].starts_with("2024,1,2,,error,"), "{csv}");
+        assert!(lines[2].ends_with(",\"no \"\"path\"\", <sorry>\""), "{csv}");
+        assert!(lines[3].starts_with("2024,2,1,7,wrong answer,"), "{csv}");
+        assert!(lines[4].starts_with("2024,2,2,,skipped,"), "{csv}");
+    }
+
+    #[test]
+    fn test_junit() {
+        let xml = junit(2024, &results());
+        assert!(xml.contains("<testsuite name=\"aoc2024\" tests=\"4\" failures=\"1\" errors=\"1\" skipped=\"1\""), "{xml}");
+        assert!(xml.contains("<testcase classname=\"aoc2024.Day1\" name=\"Day1.1\""), "{xml}");
+        assert!(xml.contains("<system-out>42</system-out>"), "{xml}");
+        assert!(xml.contains("<error type=\"error\" message=\"no &quot;path&quot;, &lt;sorry&gt;\"/>"), "{xml}");
+        assert!(xml.contains("<failure message=\"got 7, expected 8\"/>"), "{xml}");
+        assert!(xml.contains("<skipped/>"), "{xml}");
+    }
+}
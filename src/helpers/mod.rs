@@ -1,7 +0,0 @@
-pub mod matrix;
-pub mod nums;
-pub mod parallel;
-pub mod vecs;
-pub mod vector;
-
-#[macro_use] mod tiles;
\ No newline at end of file
@@ -0,0 +1,237 @@
+use std::fmt::Display;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc_helpers::error::{parse_in, AocError, ParseContext};
+use aoc_helpers::results::Elapsed;
+
+use crate::bench::Report;
+
+/// The history file used when no other path is given.
+pub const DEFAULT_PATH: &str = "bench-history.tsv";
+
+const HEADER: &str = "# date\tcommit\tyear\tname\tmedian (ns)";
+
+/// A single benchmark timing recorded in the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub date: String,
+    pub commit: String,
+    pub year: u16,
+    pub name: String,
+    pub median: Duration,
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}\t{}\t{}\t{}", self.date, self.commit, self.year, self.name, self.median.as_nanos())
+    }
+}
+
+impl FromStr for Entry {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [date, commit, year, name, median] = fields[..] else {
+            return Err(AocError::parse_in(line, line, "expected 5 tab-separated fields"));
+        };
+
+        Ok(Self {
+            date: date.to_string(),
+            commit: commit.to_string(),
+            year: parse_in(line, year, "expected a year")?,
+            name: name.to_string(),
+            median: Duration::from_nanos(parse_in(line, median, "expected a duration in nanoseconds")?),
+        })
+    }
+}
+
+/// A part which has become slower than its historical median by more than the allowed threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub name: String,
+    pub median: Duration,
+    pub baseline: Duration,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let slower = (self.median.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0;
+        write!(f, "{} is {:.1}% slower than its historical median ({} vs {})", self.name, slower, Elapsed(self.median), Elapsed(self.baseline))
+    }
+}
+
+/// The benchmark timings recorded by previous runs, stored as a tab-separated file.
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl History {
+    /// Loads the history from the given file, which is treated as empty if it doesn't exist yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, AocError> {
+        let path = path.as_ref().to_path_buf();
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(AocError::io(&path, err)),
+        };
+
+        let entries = content.lines().enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| line.parse().on_line(i + 1))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { path, entries })
+    }
+
+    /// The median of the previously recorded timings for the named benchmark.
+    pub fn median(&self, year: u16, name: &str) -> Option<Duration> {
+        let mut timings: Vec<Duration> = self.entries.iter()
+            .filter(|entry| entry.year == year && entry.name == name)
+            .map(|entry| entry.median)
+            .collect();
+
+        crate::bench::Stats::from_samples(&mut timings).map(|stats| stats.median)
+    }
+
+    /// Finds the benchmarks in the report which are more than `threshold` percent slower than their
+    /// historical median.
+    pub fn regressions(&self, year: u16, report: &Report, threshold: u32) -> Vec<Regression> {
+        report.iter()
+            .filter_map(|(name, stats)| {
+                let baseline = self.median(year, name)?;
+                let limit = baseline.as_secs_f64() * (1.0 + threshold as f64 / 100.0);
+
+                (stats.median.as_secs_f64() > limit).then(|| Regression { name: name.to_string(), median: stats.median, baseline })
+            })
+            .collect()
+    }
+
+    /// Appends the medians from the report to the history, tagged with the current commit and date.
+    pub fn record(&mut self, year: u16, report: &Report) -> Result<(), AocError> {
+        let (date, commit) = (today(), commit());
+        let entries: Vec<Entry> = report.iter()
+            .map(|(name, stats)| Entry { date: date.clone(), commit: commit.clone(), year, name: name.to_string(), median: stats.median })
+            .collect();
+
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&self.path)
+            .map_err(|err| AocError::io(&self.path, err))?;
+
+        let mut content = String::new();
+        if self.entries.is_empty() && file.metadata().is_ok_and(|meta| meta.len() == 0) {
+            content.push_str(HEADER);
+            content.push('\n');
+        }
+
+        for entry in entries.iter() {
+            content.push_str(&entry.to_string());
+            content.push('\n');
+        }
+
+        file.write_all(content.as_bytes()).map_err(|err| AocError::io(&self.path, err))?;
+        self.entries.extend(entries);
+
+        Ok(())
+    }
+}
+
+/// The short hash of the commit which is currently checked out, or "unknown" outside of a git repository.
+fn commit() -> String {
+    std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|hash| hash.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Today's UTC date, formatted as YYYY-MM-DD.
+fn today() -> String {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+
+    let (year, month, day) = civil_from_days((seconds / 86_400) as i64);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Converts a number of days since the Unix epoch into a (year, month, day) date in the Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Stats;
+
+    fn stats(micros: u64) -> Stats {
+        Stats::from_samples(&mut [Duration::from_micros(micros)]).unwrap()
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_723), (2024, 1, 1));
+        assert_eq!(civil_from_days(20_062), (2024, 12, 5));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn test_entry() {
+        let entry: Entry = "2024-12-05\tabc1234\t2024\tDay6.2\t8000".parse().unwrap();
+        assert_eq!(entry.name, "Day6.2");
+        assert_eq!(entry.median, Duration::from_micros(8));
+        assert_eq!(entry.to_string(), "2024-12-05\tabc1234\t2024\tDay6.2\t8000");
+
+        assert!("2024-12-05\tabc1234\t2024\tDay6.2".parse::<Entry>().is_err());
+        assert_eq!("2024-12-05\tabc1234\t2024\tDay6.2\tslow".parse::<Entry>().unwrap_err().location(), (None, Some(32)));
+    }
+
+    #[test]
+    fn test_regressions() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.tsv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut history = History::load(&path).unwrap();
+        for micros in [10, 12, 11] {
+            let mut report = Report::default();
+            report.add("Day6.1", stats(micros));
+            history.record(2024, &report).unwrap();
+        }
+
+        let history = History::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(history.median(2024, "Day6.1"), Some(Duration::from_micros(11)));
+        assert_eq!(history.median(2023, "Day6.1"), None);
+
+        let mut report = Report::default();
+        report.add("Day6.1", stats(12));
+        report.add("Day6.2", stats(100));
+        assert!(history.regressions(2024, &report, 10).is_empty());
+
+        let mut report = Report::default();
+        report.add("Day6.1", stats(13));
+        assert_eq!(history.regressions(2024, &report, 10), vec![Regression {
+            name: "Day6.1".to_string(),
+            median: Duration::from_micros(13),
+            baseline: Duration::from_micros(11),
+        }]);
+    }
+}
@@ -1,11 +1,561 @@
-#![feature(generic_const_exprs)]
+use std::any::Any;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
 
-#[macro_use] pub mod helpers;
-mod questions;
+use aoc_helpers::answers::KnownAnswers;
+use aoc_helpers::error::AocError;
+use aoc_helpers::filter::Filter;
+use aoc_helpers::guesses::{GuessLog, Verdict};
+use aoc_helpers::harness;
+use aoc_helpers::inputs::{Http, InputStore};
+use aoc_helpers::questions::Answer;
+use aoc_helpers::questions::{self, Tier, Year};
+use aoc_helpers::results::{self, PuzzleResult, Status};
+use aoc_helpers::submit::{self, Outcome};
+use cli::Command;
 
-#[cfg(test)]
-pub(crate) mod results;
+mod bench;
+mod cli;
+mod export;
+mod history;
+mod readme;
+mod scaffold;
+mod watch;
 
-fn main() {
-    println!("Run 'cargo watch -x test' to run all of the solutions as you make changes.");
+/// The directory which holds each year's inputs (downloaded on demand, see [`InputStore`]) and known answers.
+const INPUTS: &str = "inputs";
+
+/// The solutions for every year which has been registered with the runner, in order.
+static YEARS: &[&Year] = &[
+    &aoc2024::YEAR,
+];
+
+fn main() -> ExitCode {
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{err}");
+            eprintln!();
+            eprintln!("{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command {
+        Command::Run { year, day, part, input, examples } => run(year, day, part, input, examples),
+        Command::Test { year, filter } => run_test(year, filter),
+        Command::Bench { year, day, part, options, history, threshold } => run_bench(year, day, part, options, history, threshold),
+        Command::Export { year, day, part, format, output, include_slow } => run_export(year, day, part, format, output, include_slow),
+        Command::Readme { year, path, include_slow } => run_readme(year, path, include_slow),
+        Command::Record { year, day, part, include_slow } => run_record(year, day, part, include_slow),
+        Command::Guess { year, day, part, guess } => run_guess(year, day, part, guess),
+        Command::Submit { year, day, part, force } => run_submit(year, day, part, force),
+        Command::New { year, day, example, part1, part2 } => run_new(year, day, example, part1, part2),
+        Command::Watch { year, day, once } => run_watch(year, day, once),
+        Command::List => {
+            for year in YEARS {
+                println!("{}:", year.year);
+                for solution in year.solutions {
+                    println!("  Day {:>2}: {} ({} examples)", solution.day, solution.name, solution.examples.len());
+                }
+            }
+
+            Ok(())
+        },
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+    };
+
+    report(result)
+}
+
+/// Prints the error a command failed with (if any), and turns its result into the process's exit code.
+fn report(result: Result<(), AocError>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err.diagnostic());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Finds the registered solutions for the given year, or the latest year if none was given.
+fn find_year(year: Option<u16>) -> Result<&'static Year, AocError> {
+    match year {
+        Some(year) => YEARS.iter().copied().find(|y| y.year == year)
+            .ok_or_else(|| AocError::solve(format!("no solutions are registered for {year}"))),
+        None => YEARS.last().copied().ok_or_else(|| AocError::solve("no solutions have been registered")),
+    }
+}
+
+fn run(year: Option<u16>, day: u8, part: Option<u8>, input: Option<PathBuf>, examples: bool) -> Result<(), AocError> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let year = find_year(year)?;
+    let solution = year.get(day).ok_or(AocError::NotImplemented { day })?;
+
+    if examples {
+        return run_examples(solution, &parts);
+    }
+
+    // Known answers and previous guesses only apply to the real input, not to one which was passed in.
+    let (answers, guesses) = match input {
+        Some(_) => (KnownAnswers::default(), GuessLog::default()),
+        None => (KnownAnswers::load(KnownAnswers::path_for(INPUTS, year.year))?, GuessLog::load(GuessLog::path_for(INPUTS, year.year))?),
+    };
+
+    let input = match input {
+        Some(path) => std::fs::read_to_string(&path).map_err(|err| AocError::io(&path, err))?,
+        None => InputStore::from_env(INPUTS)?.get(year.year, day).map_err(|err| err.in_puzzle(day, None))?,
+    };
+
+    // Panics and hangs are reported like any other failure, rather than taking down the runner.
+    let timeout = solution.timeout.unwrap_or_else(results::timeout);
+    let (parsed, parse_duration) = match results::parse_with_timeout(solution.name, timeout, move || (solution.parse)(input.trim())) {
+        Ok((parsed, parse_duration)) => (Arc::<dyn Any + Send + Sync>::from(parsed), parse_duration),
+        Err(err) => {
+            println!("{}", PuzzleResult::failed(solution.name, &err));
+            return Err(err.in_puzzle(day, None));
+        },
+    };
+
+    let mut wrong = Vec::new();
+    for part in parts {
+        let (result, err) = PuzzleResult::measure_parsed_with_timeout(format!("{}.{part}", solution.name), timeout, Arc::clone(&parsed),
+            move |parsed| solution.part(part)(parsed, None));
+        let result = answers.check(result.with_parse_duration(parse_duration).for_part(day, part));
+
+        // Any doubts about the answer are printed ahead of it, so they're seen before it gets submitted.
+        for warning in result.answer().map(|answer| guesses.check(day, part, answer)).unwrap_or_default() {
+            eprintln!("warning[{}]: {warning}", result.name());
+        }
+        println!("{result}");
+
+        if let Some(err) = err {
+            return Err(err.in_puzzle(day, Some(part)));
+        }
+
+        if result.status() == Status::WrongAnswer {
+            wrong.push(part);
+        }
+    }
+
+    match wrong[..] {
+        [] => Ok(()),
+        [part] => Err(AocError::solve(format!("the answer doesn't match the one recorded in {}", answers.path().display())).in_puzzle(day, Some(part))),
+        _ => Err(AocError::solve(format!("the answers don't match the ones recorded in {}", answers.path().display())).in_puzzle(day, None)),
+    }
+}
+
+/// Records the verdict for an answer which was given for a part, or lists the guesses made so far.
+fn run_guess(year: Option<u16>, day: u8, part: Option<u8>, guess: Option<(Answer, Verdict)>) -> Result<(), AocError> {
+    let year = find_year(year)?;
+    let mut log = GuessLog::load(GuessLog::path_for(INPUTS, year.year))?;
+
+    if let Some((answer, verdict)) = guess {
+        let part = part.ok_or_else(|| AocError::solve("a '--part' is needed to record a guess"))?;
+
+        for warning in log.check(day, part, &answer) {
+            eprintln!("warning[Day{day}.{part}]: {warning}");
+        }
+
+        log.record(day, part, &answer, verdict)?;
+    }
+
+    for part in part.map(|part| vec![part]).unwrap_or_else(|| vec![1, 2]) {
+        let guesses: Vec<_> = log.guesses(day, part).collect();
+        if guesses.is_empty() {
+            continue;
+        }
+
+        println!("Day{day}.{part}:");
+        for guess in guesses {
+            println!("  {:<10} {}", guess.verdict, guess.answer);
+        }
+
+        match log.bounds(day, part) {
+            (None, None) => {},
+            (low, high) => println!("  The answer is between {} and {}.",
+                low.map(|low| low.to_string()).unwrap_or_else(|| "?".to_string()),
+                high.map(|high| high.to_string()).unwrap_or_else(|| "?".to_string())),
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a part against its input and submits the answer, recording the verdict in the guess log (and the
+/// answer in the known answers, once it has been accepted).
+fn run_submit(year: Option<u16>, day: u8, part: u8, force: bool) -> Result<(), AocError> {
+    let year = find_year(year)?;
+    let http = Http::from_env()?.ok_or_else(|| AocError::solve(format!("set AOC_SESSION to the site's session cookie, and AOC_URL to the http:// address of a local TLS proxy for {}, to submit answers", Http::SITE)))?;
+    let mut answers = KnownAnswers::load(KnownAnswers::path_for(INPUTS, year.year))?;
+    let mut log = GuessLog::load(GuessLog::path_for(INPUTS, year.year))?;
+
+    // The day was asked for by name, so it's run even if it's marked as slow.
+    let result = run_all(year, Some(day), Some(part), true)?.remove(0);
+    println!("{result}");
+
+    let answer = match (result.status(), result.answer()) {
+        (Status::Ok, Some(answer)) => answer,
+        (Status::WrongAnswer, _) => return Err(AocError::solve(format!("the answer doesn't match the one recorded in {}", answers.path().display())).in_puzzle(day, Some(part))),
+        (status, _) => return Err(AocError::solve(format!("there is no answer to submit ({status})")).in_puzzle(day, Some(part))),
+    };
+
+    if answers.get(day, part).is_some() {
+        println!("This answer has already been accepted.");
+        return Ok(());
+    }
+
+    // A wrong answer locks out further submissions for a while, so don't give one we know must be wrong.
+    let warnings = log.check(day, part, answer);
+    for warning in warnings.iter() {
+        eprintln!("warning[{}]: {warning}", result.name());
+    }
+
+    if !warnings.is_empty() && !force {
+        return Err(AocError::solve("not submitting an answer which must be wrong (use '--force' to submit it anyway)").in_puzzle(day, Some(part)));
+    }
+
+    let outcome = submit::submit(&http, year.year, day, part, answer)?;
+    println!("{}: {outcome}", result.name());
+
+    match outcome {
+        Outcome::Checked(verdict) => {
+            log.record(day, part, answer, verdict)?;
+            if verdict.is_wrong() {
+                return Err(AocError::solve(format!("the answer was {verdict}")).in_puzzle(day, Some(part)));
+            }
+
+            answers.set(day, part, answer);
+            answers.save()?;
+            println!("Recorded the answer in {}", answers.path().display());
+            Ok(())
+        },
+        Outcome::RateLimited { .. } => Err(AocError::solve("the answer was not checked, so try again later").in_puzzle(day, Some(part))),
+        Outcome::WrongLevel => Ok(()),
+    }
+}
+
+/// Generates a new day from its year's template, registers it, and creates an empty placeholder for its
+/// input (which the input store fills in once it can be downloaded).
+fn run_new(year: Option<u16>, day: u8, example: Option<PathBuf>, part1: Option<String>, part2: Option<String>) -> Result<(), AocError> {
+    let year = find_year(year)?;
+    if !(1..=25).contains(&day) {
+        return Err(AocError::solve(format!("{day} is not a day of the event")));
+    }
+
+    let src = PathBuf::from(format!("aoc{}", year.year)).join("src");
+    let module = src.join(format!("day{day}.rs"));
+    if module.exists() || year.get(day).is_some() {
+        return Err(AocError::solve(format!("Day {day} already exists, so it won't be overwritten")));
+    }
+
+    let example = match example {
+        Some(path) if path.as_os_str() == "-" => Some(std::io::read_to_string(std::io::stdin()).map_err(|err| AocError::io("<stdin>", err))?),
+        Some(path) => Some(std::fs::read_to_string(&path).map_err(|err| AocError::io(&path, err))?),
+        None => None,
+    };
+
+    let template_path = src.join("day_template.rs");
+    let template = std::fs::read_to_string(&template_path).map_err(|err| AocError::io(&template_path, err))?;
+    let lib_path = src.join("lib.rs");
+    let lib = std::fs::read_to_string(&lib_path).map_err(|err| AocError::io(&lib_path, err))?;
+    let lib = scaffold::register(&lib, day)?;
+
+    let content = scaffold::generate(&template, day, example.as_deref(), part1.as_deref(), part2.as_deref());
+    std::fs::write(&module, content).map_err(|err| AocError::io(&module, err))?;
+    std::fs::write(&lib_path, lib).map_err(|err| AocError::io(&lib_path, err))?;
+    println!("Created {} and registered it in {}", module.display(), lib_path.display());
+
+    let input = InputStore::new(INPUTS).path(year.year, day);
+    if !input.exists() {
+        if let Some(parent) = input.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| AocError::io(parent, err))?;
+        }
+
+        std::fs::write(&input, "").map_err(|err| AocError::io(&input, err))?;
+        println!("Created an empty placeholder for the input at {}", input.display());
+    }
+
+    Ok(())
+}
+
+/// Runs the examples and real inputs which the filter lets through, in the same way as the `solutions`
+/// test target.
+fn run_test(year: Option<u16>, filter: Filter) -> Result<(), AocError> {
+    let year = find_year(year)?;
+    let results = harness::run(year, INPUTS.as_ref(), &filter)?;
+
+    match filter.is_everything() {
+        true => println!("Results for {}:", year.year),
+        false => println!("Results for {} ({filter}):", year.year),
+    }
+    println!("{results}");
+
+    match results.failures() {
+        0 => Ok(()),
+        n => Err(AocError::solve(format!("{n} puzzle(s) need fixing"))),
+    }
+}
+
+/// Re-runs a day whenever its source or input changes. Since a change to the source needs a rebuild, each
+/// run rebuilds the runner with cargo and then starts the new binary with '--once' to run the day.
+fn run_watch(year: Option<u16>, day: u8, once: bool) -> Result<(), AocError> {
+    let year = find_year(year)?;
+    let solution = year.get(day).ok_or(AocError::NotImplemented { day })?;
+
+    if once {
+        let results = harness::run_solutions(year.year, std::slice::from_ref(solution), INPUTS.as_ref(), &Filter::from_env()?)?;
+        println!("{}", watch::summary(&results));
+        return Ok(());
+    }
+
+    let source = PathBuf::from(format!("aoc{}", year.year)).join("src").join(format!("day{day}.rs"));
+    let mut watcher = watch::Watcher::new(vec![source, InputStore::new(INPUTS).path(year.year, day)]);
+    let exe = std::env::current_exe().map_err(|err| AocError::io("the runner", err))?;
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    println!("Watching {} (press Ctrl+C to stop)...", solution.name);
+    loop {
+        let changed: Vec<String> = watcher.poll().iter().map(|path| path.display().to_string()).collect();
+        if changed.is_empty() {
+            std::thread::sleep(watch::INTERVAL);
+            continue;
+        }
+
+        println!();
+        println!("== {} changed ==", changed.join(", "));
+
+        let mut build = std::process::Command::new(&cargo);
+        build.args(["build", "--quiet", "--bin", "aoc"]);
+        if !cfg!(debug_assertions) {
+            build.arg("--release");
+        }
+
+        match build.status() {
+            Ok(status) if status.success() => {
+                let status = std::process::Command::new(&exe)
+                    .args(["watch", "--year", &year.year.to_string(), "--day", &day.to_string(), "--once"])
+                    .status();
+                if let Err(err) = status {
+                    eprintln!("{}", AocError::io(&exe, err));
+                }
+            },
+            Ok(_) => println!("The build failed, so waiting for the next change."),
+            Err(err) => return Err(AocError::solve(format!("could not run '{cargo} build'")).caused_by(err)),
+        }
+    }
+}
+
+/// Runs the requested days and records their answers as the known answers for the year, so that later
+/// runs are checked against them.
+fn run_record(year: Option<u16>, day: Option<u8>, part: Option<u8>, include_slow: bool) -> Result<(), AocError> {
+    let year = find_year(year)?;
+    let mut answers = KnownAnswers::load(KnownAnswers::path_for(INPUTS, year.year))?;
+
+    let mut recorded = 0;
+    for result in run_all(year, day, part, include_slow)? {
+        let (Some(day), Some(part)) = (result.day(), result.part()) else {
+            continue;
+        };
+
+        match (result.status(), result.answer()) {
+            (Status::Ok | Status::WrongAnswer, Some(answer)) => {
+                if let Some(previous) = answers.set(day, part, answer).filter(|previous| answer != previous) {
+                    println!("{}: {answer} (was {previous})", result.name());
+                } else {
+                    println!("{}: {answer}", result.name());
+                }
+
+                recorded += 1;
+            },
+            (status, _) => println!("{}: not recorded ({status})", result.name()),
+        }
+    }
+
+    answers.save()?;
+    println!("Recorded {recorded} answer(s) in {}", answers.path().display());
+    Ok(())
+}
+
+/// Runs each of the requested days against their inputs, collecting a result for every part. Unlike
+/// [`run`], a day which fails (or takes longer than its time budget) doesn't stop the others from running.
+///
+/// Each day's input is parsed once and shared between its parts (see [`harness::run_parts`]). Days which are
+/// marked as slow are reported as such without being run, unless `include_slow` is set.
+fn run_all(year: &Year, day: Option<u8>, part: Option<u8>, include_slow: bool) -> Result<Vec<PuzzleResult>, AocError> {
+    let solutions = match day {
+        Some(day) => vec![year.get(day).ok_or(AocError::NotImplemented { day })?],
+        None => year.solutions.iter().collect(),
+    };
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let answers = KnownAnswers::load(KnownAnswers::path_for(INPUTS, year.year))?;
+    let store = InputStore::from_env(INPUTS)?;
+
+    let mut results = Vec::new();
+    for solution in solutions {
+        match solution.tier {
+            Tier::Skip(reason) => {
+                results.extend(parts.iter().map(|&part| PuzzleResult::skipped(format!("{}.{part}", solution.name)).because(reason).for_part(solution.day, part)));
+                continue;
+            },
+            Tier::Slow(reason) if !include_slow => {
+                results.extend(parts.iter().map(|&part| PuzzleResult::slow(format!("{}.{part}", solution.name)).because(reason).for_part(solution.day, part)));
+                continue;
+            },
+            _ => {},
+        }
+
+        let input = store.get(year.year, solution.day).map_err(Arc::new);
+        let names = parts.iter().map(|&part| (part, format!("{}.{part}", solution.name))).collect();
+        results.extend(harness::run_parts(solution, input, names, None).into_iter().map(|result| answers.check(result)));
+    }
+
+    Ok(results)
+}
+
+fn run_export(year: Option<u16>, day: Option<u8>, part: Option<u8>, format: export::Format, output: Option<PathBuf>, include_slow: bool) -> Result<(), AocError> {
+    let year = find_year(year)?;
+    let results = run_all(year, day, part, include_slow)?;
+    let rendered = format.render(year.year, &results);
+
+    match output {
+        Some(path) => std::fs::write(&path, rendered).map_err(|err| AocError::io(&path, err)),
+        None => {
+            print!("{rendered}");
+            Ok(())
+        }
+    }
+}
+
+fn run_readme(year: Option<u16>, path: PathBuf, include_slow: bool) -> Result<(), AocError> {
+    let year = find_year(year)?;
+    let results = run_all(year, None, None, include_slow)?;
+
+    let content = std::fs::read_to_string(&path).map_err(|err| AocError::io(&path, err))?;
+    let content = readme::rewrite(&content, &readme::table(year.year, &results))?;
+    std::fs::write(&path, content).map_err(|err| AocError::io(&path, err))?;
+
+    println!("Updated the results for {} days in {}", year.solutions.len(), path.display());
+    Ok(())
+}
+
+fn run_bench(year: Option<u16>, day: Option<u8>, part: Option<u8>, options: bench::Options, history: Option<PathBuf>, threshold: u32) -> Result<(), AocError> {
+    let year = find_year(year)?;
+    let mut history = history.map(history::History::load).transpose()?;
+
+    let solutions = match day {
+        Some(day) => vec![year.get(day).ok_or(AocError::NotImplemented { day })?],
+        None => year.solutions.iter().collect(),
+    };
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let store = InputStore::from_env(INPUTS)?;
+    let mut report = bench::Report::default();
+    for solution in solutions {
+        // When benchmarking a whole year, days which are skipped or too slow aren't run either.
+        match solution.tier {
+            Tier::Skip(reason) | Tier::Slow(reason) if day.is_none() => {
+                eprintln!("Skipping {}: {reason}", solution.name);
+                continue;
+            },
+            _ => {},
+        }
+
+        let input = match store.get(year.year, solution.day) {
+            Ok(input) => input,
+            // When benchmarking a whole year, days without an input are skipped rather than failing the run.
+            Err(err) if day.is_none() => {
+                eprintln!("Skipping {}: {err}", solution.name);
+                continue;
+            },
+            Err(err) => return Err(err.in_puzzle(solution.day, None)),
+        };
+
+        let timeout = solution.timeout.unwrap_or_else(results::timeout);
+        let input: Arc<str> = Arc::from(input.trim());
+        eprintln!("Benchmarking {}...", solution.name);
+
+        // Every run is timed on its own thread within the day's time budget, so a day which panics or hangs
+        // is reported as failed without stopping the rest of the benchmarks.
+        let parse = || {
+            let input = Arc::clone(&input);
+            results::parse_with_timeout(solution.name, timeout, move || (solution.parse)(&input))
+        };
+
+        let parsed = parse().and_then(|(parsed, _)| {
+            let stats = bench::measure(options, || parse().map(|(_, duration)| duration))?;
+            Ok((Arc::<dyn Any + Send + Sync>::from(parsed), stats))
+        });
+
+        let parsed = match parsed {
+            Ok((parsed, stats)) => {
+                report.add(format!("{} (parse)", solution.name), stats);
+                parsed
+            },
+            Err(err) => {
+                report.fail(format!("{} (parse)", solution.name), &err);
+                continue;
+            },
+        };
+
+        for &part in parts.iter() {
+            let name = format!("{}.{part}", solution.name);
+            let run = || match PuzzleResult::measure_parsed_with_timeout(&name, timeout, Arc::clone(&parsed), move |parsed| solution.part(part)(parsed, None)) {
+                (_, Some(err)) => Err(err),
+                (result, None) => Ok(result.duration()),
+            };
+
+            match bench::measure(options, run) {
+                Ok(stats) => report.add(name, stats),
+                Err(err) => {
+                    report.fail(name, &err);
+                },
+            }
+        }
+    }
+
+    println!("{report}");
+
+    if let Some(history) = history.as_mut() {
+        let regressions = history.regressions(year.year, &report, threshold);
+        if !regressions.is_empty() {
+            println!();
+            for regression in regressions {
+                println!("REGRESSION: {regression}");
+            }
+        }
+
+        history.record(year.year, &report)?;
+    }
+
+    match report.failures() {
+        0 => Ok(()),
+        failures => Err(AocError::solve(format!("{failures} benchmark(s) failed"))),
+    }
+}
+
+fn run_examples(solution: &questions::Solution, parts: &[u8]) -> Result<(), AocError> {
+    for &part in parts {
+        harness::check_examples(solution, part, INPUTS.as_ref())?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,100 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc_helpers::error::AocError;
+use aoc_helpers::results::{Elapsed, PuzzleResult, Status};
+
+/// Marks the start of the generated section of the README.
+pub const START_MARKER: &str = "<!-- results:start -->";
+/// Marks the end of the generated section of the README.
+pub const END_MARKER: &str = "<!-- results:end -->";
+
+/// Renders a Markdown table with a row for each day, showing how each part went and how long the day took
+/// to run (including parsing), followed by the total runtime.
+pub fn table(year: u16, results: &[PuzzleResult]) -> String {
+    let mut days: Vec<u8> = results.iter().filter_map(PuzzleResult::day).collect();
+    days.dedup();
+
+    let mut out = String::new();
+    writeln!(out, "| {year} | Part 1 | Part 2 | Runtime |").unwrap();
+    writeln!(out, "|:-----|:------:|:------:|--------:|").unwrap();
+
+    let mut total = Duration::ZERO;
+    for day in days {
+        let parts: Vec<&PuzzleResult> = results.iter().filter(|result| result.day() == Some(day)).collect();
+        // The day's input is parsed once for both parts, so its parse time is only counted once.
+        let parse = parts.iter().map(|result| result.parse_duration()).max().unwrap_or_default();
+        let runtime: Duration = parts.iter().map(|result| result.duration()).sum::<Duration>() + parse;
+        total += runtime;
+
+        let status = |part: u8| match parts.iter().find(|result| result.part() == Some(part)).map(|result| result.status()) {
+            Some(Status::Ok) => "✅",
+            Some(Status::WrongAnswer) => "❌",
+            Some(Status::Error) => "⚠️",
+            Some(Status::Panicked) => "💥",
+            Some(Status::Skipped) => "⏭️",
+            Some(Status::Timeout) => "⏱️",
+            Some(Status::MissingInput) => "📭",
+            Some(Status::Slow) => "🐢",
+            None => "—",
+        };
+
+        writeln!(out, "| Day {day} | {} | {} | {} |", status(1), status(2), Elapsed(runtime)).unwrap();
+    }
+
+    writeln!(out, "| **Total** | | | **{}** |", Elapsed(total)).unwrap();
+    out
+}
+
+/// Replaces everything between the results markers in `readme` with the given table.
+pub fn rewrite(readme: &str, table: &str) -> Result<String, AocError> {
+    let missing = || AocError::solve(format!("the README must contain a '{START_MARKER}' line followed by a '{END_MARKER}' line"));
+
+    let start = readme.find(START_MARKER).ok_or_else(missing)? + START_MARKER.len();
+    let end = readme[start..].find(END_MARKER).ok_or_else(missing)? + start;
+
+    Ok(format!("{}\n{}{}", &readme[..start], table, &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let results = vec![
+            PuzzleResult::measure("Day1.1", || Ok(1)).0.for_part(1, 1),
+            PuzzleResult::measure("Day1.2", || Err::<i64, _>(AocError::solve("oops"))).0.for_part(1, 2),
+            PuzzleResult::measure("Day2.1", || Ok(2)).0.for_part(2, 1),
+        ];
+
+        let table = table(2024, &results);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], "| 2024 | Part 1 | Part 2 | Runtime |");
+        assert!(lines[2].starts_with("| Day 1 | ✅ | ⚠️ | "), "{table}");
+        assert!(lines[3].starts_with("| Day 2 | ✅ | — | "), "{table}");
+        assert!(lines[4].starts_with("| **Total** | | | **"), "{table}");
+    }
+
+    #[test]
+    fn test_parse_counted_once() {
+        let parse = Duration::from_millis(40);
+        let results = vec![
+            PuzzleResult::measure("Day3.1", || Ok(1)).0.with_parse_duration(parse).for_part(3, 1),
+            PuzzleResult::measure("Day3.2", || Ok(2)).0.with_parse_duration(parse).for_part(3, 2),
+        ];
+
+        let table = table(2024, &results);
+        assert!(table.contains("| Day 3 | ✅ | ✅ | 40ms |"), "{table}");
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let readme = format!("# Title\n{START_MARKER}\nold\ntable\n{END_MARKER}\nFooter\n");
+        assert_eq!(rewrite(&readme, "new\n").unwrap(), format!("# Title\n{START_MARKER}\nnew\n{END_MARKER}\nFooter\n"));
+
+        assert!(rewrite("# Title\n", "new\n").is_err());
+        assert!(rewrite(&format!("{END_MARKER}\n{START_MARKER}\n"), "new\n").is_err());
+    }
+}
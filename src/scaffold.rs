@@ -0,0 +1,103 @@
+use aoc_helpers::error::AocError;
+
+/// The placeholder name used for the day throughout the template.
+const PLACEHOLDER: &str = "DayN";
+
+/// Fills in the day template for the given day, optionally replacing its empty `validate` block with an
+/// example input and the answers it should produce.
+pub fn generate(template: &str, day: u8, example: Option<&str>, part1: Option<&str>, part2: Option<&str>) -> String {
+    let mut out = template.replace(PLACEHOLDER, &format!("Day{day}"));
+
+    if let Some(example) = example {
+        let lines: String = example.trim_matches('\n').lines()
+            .map(|line| match line.trim_end() {
+                "" => "\n".to_string(),
+                line => format!("        {line}\n"),
+            })
+            .collect();
+        out = out.replacen("input: r#\"\n        \"#", &format!("input: r#\"\n{lines}        \"#"), 1);
+    }
+
+    for (name, answer) in [("part1", part1), ("part2", part2)] {
+        if let Some(answer) = answer {
+            out = out.replacen(&format!("{name}: \"\""), &format!("{name}: {}", literal(answer)), 1);
+        }
+    }
+
+    out
+}
+
+/// Registers a new day with its year's `lib.rs`, adding its `pub mod` declaration and its solution to the
+/// `YEAR` registry in day order.
+pub fn register(lib: &str, day: u8) -> Result<String, AocError> {
+    let module = |line: &str| line.strip_prefix("pub mod day").and_then(|rest| rest.strip_suffix(';')).and_then(|n| n.parse::<u8>().ok());
+    let solution = |line: &str| line.trim().strip_prefix("day").and_then(|rest| rest.strip_suffix("::SOLUTION,")).and_then(|n| n.parse::<u8>().ok());
+
+    let lines: Vec<&str> = lib.lines().collect();
+    if lines.iter().any(|line| module(line) == Some(day)) {
+        return Err(AocError::solve(format!("day{day} has already been registered")));
+    }
+
+    // Each new line goes after the last existing one for an earlier day (or before the first, if there is none).
+    let position = |find: &dyn Fn(&str) -> Option<u8>, what: &str| {
+        let matching: Vec<usize> = (0..lines.len()).filter(|&i| find(lines[i]).is_some()).collect();
+        let first = *matching.first().ok_or_else(|| AocError::solve(format!("could not find where the {what} go")))?;
+        Ok::<_, AocError>(matching.iter().copied().rfind(|&i| find(lines[i]) < Some(day)).map_or(first, |i| i + 1))
+    };
+
+    let module_at = position(&module, "mod declarations")?;
+    let solution_at = position(&solution, "registered solutions")?;
+    let indent = lines.iter().find(|line| solution(line).is_some()).map(|line| &line[..line.len() - line.trim_start().len()]).unwrap_or_default();
+
+    let mut out = Vec::with_capacity(lines.len() + 2);
+    for (i, line) in lines.iter().enumerate() {
+        if i == module_at {
+            out.push(format!("pub mod day{day};"));
+        }
+
+        if i == solution_at {
+            out.push(format!("{indent}day{day}::SOLUTION,"));
+        }
+
+        out.push(line.to_string());
+    }
+
+    Ok(out.join("\n") + "\n")
+}
+
+/// How an answer is written in the `validate` block: numbers as they are, anything else as a string.
+fn literal(answer: &str) -> String {
+    match answer.trim().parse::<i128>() {
+        Ok(number) => number.to_string(),
+        Err(_) => format!("{:?}", answer.trim()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = "question!(DayN, validate = [\n    {\n        input: r#\"\n        \"#,\n        part1: \"\",\n        part2: \"\"\n    }\n]);\n\nimpl Question for DayN {}\n";
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(TEMPLATE, 8, None, None, None), TEMPLATE.replace("DayN", "Day8"));
+        assert_eq!(generate(TEMPLATE, 8, Some("..#\n\n#..\n"), Some("14"), Some("abc")),
+            "question!(Day8, validate = [\n    {\n        input: r#\"\n        ..#\n\n        #..\n        \"#,\n        part1: 14,\n        part2: \"abc\"\n    }\n]);\n\nimpl Question for Day8 {}\n");
+    }
+
+    #[test]
+    fn test_register() {
+        let lib = "macro_rules! year {}\n\npub mod day1;\npub mod day7;\n\npub static YEAR: Year = Year {\n    solutions: &[\n        day1::SOLUTION,\n        day7::SOLUTION,\n    ],\n};\n";
+
+        assert_eq!(register(lib, 8).unwrap(), lib
+            .replace("pub mod day7;\n", "pub mod day7;\npub mod day8;\n")
+            .replace("day7::SOLUTION,\n", "day7::SOLUTION,\n        day8::SOLUTION,\n"));
+        assert_eq!(register(lib, 3).unwrap(), lib
+            .replace("pub mod day7;\n", "pub mod day3;\npub mod day7;\n")
+            .replace("        day7::SOLUTION,\n", "        day3::SOLUTION,\n        day7::SOLUTION,\n"));
+
+        assert!(register(lib, 7).is_err());
+        assert!(register("fn main() {}\n", 1).is_err());
+    }
+}
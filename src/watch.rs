@@ -0,0 +1,139 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use aoc_helpers::results::{Elapsed, ResultContainer, Status};
+
+/// How often the watched files are checked for changes.
+pub const INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Polls a set of files for changes by comparing their modification times, without any platform-specific
+/// file watching.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    seen: Vec<Option<SystemTime>>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let seen = vec![None; paths.len()];
+        Self { paths, seen }
+    }
+
+    /// The files which have been created, modified or removed since the last poll. Every existing file
+    /// counts as changed the first time round.
+    pub fn poll(&mut self) -> Vec<&PathBuf> {
+        let mut changed = Vec::new();
+        for (path, seen) in self.paths.iter().zip(self.seen.iter_mut()) {
+            let modified = std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+            if modified != *seen {
+                *seen = modified;
+                changed.push(path);
+            }
+        }
+
+        changed
+    }
+}
+
+/// A compact rendering of a day's results: a line per example and part marking whether it passed, with a
+/// diff of the answer for any which didn't.
+pub fn summary(results: &ResultContainer) -> String {
+    let mut out = String::new();
+
+    for result in results.iter() {
+        let mark = match result.status() {
+            Status::Ok => "✓",
+            Status::Skipped | Status::Slow | Status::MissingInput => "-",
+            _ => "✗",
+        };
+
+        write!(out, "{mark} {}", result.name()).unwrap();
+        match (result.status(), result.answer(), result.expected(), result.error()) {
+            (Status::WrongAnswer, Some(answer), Some(expected), _) => {
+                writeln!(out).unwrap();
+                out.push_str(&diff(expected, &answer.to_string()));
+                continue;
+            },
+            (_, _, _, Some(err)) => write!(out, ": {err}").unwrap(),
+            (_, Some(answer), _, _) if answer.is_grid() => {},
+            (_, Some(answer), _, _) => write!(out, ": {answer}").unwrap(),
+            _ => {},
+        }
+
+        match result.status() {
+            Status::Skipped | Status::Slow | Status::MissingInput => writeln!(out).unwrap(),
+            _ => writeln!(out, " ({})", Elapsed(result.duration() + result.parse_duration())).unwrap(),
+        }
+    }
+
+    let passed = results.iter().filter(|result| result.status() == Status::Ok).count();
+    match results.failures() {
+        0 => write!(out, "all {passed} passed").unwrap(),
+        failures => write!(out, "{failures} failed, {passed} passed").unwrap(),
+    }
+
+    out
+}
+
+/// Shows where an answer differs from the expected one, line by line so that grids can be compared.
+fn diff(expected: &str, actual: &str) -> String {
+    let (expected, actual): (Vec<&str>, Vec<&str>) = (expected.trim_end().lines().collect(), actual.trim_end().lines().collect());
+
+    let mut out = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(expected), Some(actual)) if expected == actual => writeln!(out, "    {actual}").unwrap(),
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    writeln!(out, "  - {expected}").unwrap();
+                }
+
+                if let Some(actual) = actual {
+                    writeln!(out, "  + {actual}").unwrap();
+                }
+            },
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_helpers::results::PuzzleResult;
+
+    use super::*;
+
+    #[test]
+    fn test_watcher() {
+        let path = std::env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        let mut watcher = Watcher::new(vec![path.clone()]);
+        assert!(watcher.poll().is_empty());
+
+        std::fs::write(&path, "1").unwrap();
+        assert_eq!(watcher.poll(), vec![&path]);
+        assert!(watcher.poll().is_empty());
+
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(5)).unwrap();
+        assert_eq!(watcher.poll(), vec![&path]);
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(watcher.poll(), vec![&path]);
+    }
+
+    #[test]
+    fn test_summary() {
+        let results: ResultContainer = [
+            PuzzleResult::measure("Day5.1 (example 1)", || Ok(143)).0,
+            PuzzleResult::measure("Day5.1", || Ok(4872)).0.expecting("4871"),
+            PuzzleResult::measure("Day5.2", || Ok("#.\n.#\n".to_string())).0.expecting("#.\n##"),
+        ].into_iter().collect();
+
+        let summary = summary(&results);
+        let lines: Vec<&str> = summary.lines().collect();
+        assert!(lines[0].starts_with("✓ Day5.1 (example 1): 143 ("), "{summary}");
+        assert_eq!(lines[1..], ["✗ Day5.1", "  - 4871", "  + 4872", "✗ Day5.2", "    #.", "  - ##", "  + .#", "2 failed, 1 passed"], "{summary}");
+    }
+}